@@ -17,7 +17,24 @@ try {
 
 // BN254 scalar field prime — must match deadDropNoirService.ts
 const BN254_FR = 21888242871839275222246405745257275088548364400416034343698204186575808495617n;
-const GRID_SIZE = 100;
+
+// Must match PUBLIC_INPUTS_VERSION / NUM_PUBLIC_INPUTS in contracts/dead-drop/src/lib.rs
+// and circuits/dead_drop/src/main.nr.
+const PUBLIC_INPUTS_VERSION = 1;
+const NUM_PUBLIC_INPUTS = 11;
+
+// Distance metrics, matching the contract's METRIC_* constants.
+const METRIC_MANHATTAN = 0;
+const METRIC_CHEBYSHEV = 1;
+const METRIC_EUCLIDEAN_SQ = 2;
+
+// The contract's GameConfig::default() board rules.
+const DEFAULT_RULES = Object.freeze({
+  toroidal: true,
+  grid_width: 100,
+  grid_height: 100,
+  metric: METRIC_MANHATTAN,
+});
 
 // Paths to Groth16 artifacts
 const NOIR_CLI = path.resolve(__dirname, "../../noir-groth16-reference/target/release/noir-cli");
@@ -41,10 +58,34 @@ function fieldHexNoPrefix(value) {
   return BigInt(value).toString(16).padStart(64, "0");
 }
 
-function wrappedManhattan(px, py, rx, ry) {
-  const dx = Math.abs(px - rx);
-  const dy = Math.abs(py - ry);
-  return Math.min(dx, GRID_SIZE - dx) + Math.min(dy, GRID_SIZE - dy);
+function resolveRules(input) {
+  return {
+    toroidal: input.toroidal ?? DEFAULT_RULES.toroidal,
+    grid_width: input.grid_width ?? DEFAULT_RULES.grid_width,
+    grid_height: input.grid_height ?? DEFAULT_RULES.grid_height,
+    metric: input.metric ?? DEFAULT_RULES.metric,
+  };
+}
+
+// Same as axis_diff in the circuit: wrap around the edge only on a toroidal board.
+function axisDiff(a, b, toroidal, gridSize) {
+  const d = Math.abs(a - b);
+  return toroidal && d * 2 > gridSize ? gridSize - d : d;
+}
+
+function computeDistance(px, py, rx, ry, rules) {
+  const dx = axisDiff(px, rx, rules.toroidal, rules.grid_width);
+  const dy = axisDiff(py, ry, rules.toroidal, rules.grid_height);
+  switch (rules.metric) {
+    case METRIC_MANHATTAN:
+      return dx + dy;
+    case METRIC_CHEBYSHEV:
+      return Math.max(dx, dy);
+    case METRIC_EUCLIDEAN_SQ:
+      return dx * dx + dy * dy;
+    default:
+      throw new Error(`unsupported metric: ${rules.metric}`);
+  }
 }
 
 function computeDropCommitment(dropX, dropY, dropSaltHex) {
@@ -65,40 +106,46 @@ function decodeU32FieldHex(hex) {
 }
 
 function decodePublicInputsSemantics(publicInputsHex) {
-  if (!Array.isArray(publicInputsHex) || publicInputsHex.length !== 6) {
-    throw new Error(`expected 6 public inputs, got ${Array.isArray(publicInputsHex) ? publicInputsHex.length : "non-array"}`);
+  if (!Array.isArray(publicInputsHex) || publicInputsHex.length !== NUM_PUBLIC_INPUTS) {
+    throw new Error(
+      `expected ${NUM_PUBLIC_INPUTS} public inputs, got ${Array.isArray(publicInputsHex) ? publicInputsHex.length : "non-array"}`
+    );
   }
 
   return {
-    session_id: decodeU32FieldHex(publicInputsHex[0]),
-    turn: decodeU32FieldHex(publicInputsHex[1]),
-    ping_x: decodeU32FieldHex(publicInputsHex[2]),
-    ping_y: decodeU32FieldHex(publicInputsHex[3]),
-    drop_commitment_hex: String(publicInputsHex[4]).toLowerCase(),
-    expected_distance: decodeU32FieldHex(publicInputsHex[5]),
+    public_inputs_version: decodeU32FieldHex(publicInputsHex[0]),
+    session_id: decodeU32FieldHex(publicInputsHex[1]),
+    turn: decodeU32FieldHex(publicInputsHex[2]),
+    ping_x: decodeU32FieldHex(publicInputsHex[3]),
+    ping_y: decodeU32FieldHex(publicInputsHex[4]),
+    drop_commitment_hex: String(publicInputsHex[5]).toLowerCase(),
+    expected_distance: decodeU32FieldHex(publicInputsHex[6]),
+    toroidal: decodeU32FieldHex(publicInputsHex[7]) === 1,
+    grid_width: decodeU32FieldHex(publicInputsHex[8]),
+    grid_height: decodeU32FieldHex(publicInputsHex[9]),
+    metric: decodeU32FieldHex(publicInputsHex[10]),
   };
 }
 
 function validatePublicInputsContractLayout(input, distance, publicInputsHex) {
+  const rules = resolveRules(input);
   const expected = {
+    public_inputs_version: PUBLIC_INPUTS_VERSION,
     session_id: input.session_id,
     turn: input.turn,
     ping_x: input.ping_x,
     ping_y: input.ping_y,
     drop_commitment_hex: String(input.drop_commitment_hex).toLowerCase(),
     expected_distance: distance,
+    ...rules,
   };
 
   const actual = decodePublicInputsSemantics(publicInputsHex);
 
-  const matches = {
-    session_id: actual.session_id === expected.session_id,
-    turn: actual.turn === expected.turn,
-    ping_x: actual.ping_x === expected.ping_x,
-    ping_y: actual.ping_y === expected.ping_y,
-    drop_commitment_hex: actual.drop_commitment_hex === expected.drop_commitment_hex,
-    expected_distance: actual.expected_distance === expected.expected_distance,
-  };
+  const matches = {};
+  for (const key of Object.keys(expected)) {
+    matches[key] = actual[key] === expected[key];
+  }
 
   const ok = Object.values(matches).every(Boolean);
   if (!ok) {
@@ -110,19 +157,12 @@ function validatePublicInputsContractLayout(input, distance, publicInputsHex) {
     });
     throw new Error(
       "Prover artifact/public input schema mismatch: generated public signals do not match Dead Drop contract layout " +
-      "[session_id, turn, ping_x, ping_y, drop_commitment, expected_distance]. " +
+      "[version, session_id, turn, ping_x, ping_y, drop_commitment, expected_distance, toroidal, grid_width, grid_height, metric]. " +
       "Regenerate Groth16 artifacts (zkey/vkey) for the current circuit and update the verifier key on-chain."
     );
   }
 
-  console.log("[prover] Public input layout verified", {
-    session_id: actual.session_id,
-    turn: actual.turn,
-    ping_x: actual.ping_x,
-    ping_y: actual.ping_y,
-    drop_commitment_hex: actual.drop_commitment_hex,
-    expected_distance: actual.expected_distance,
-  });
+  console.log("[prover] Public input layout verified", actual);
 
   return { expected, actual, matches };
 }
@@ -136,15 +176,19 @@ function validatePublicInputsContractLayout(input, distance, publicInputsHex) {
  *   session_id, turn, ping_x, ping_y
  *   drop_x, drop_y, drop_salt_hex
  *   drop_commitment_hex (32 bytes hex, no 0x)
+ *   toroidal, grid_width, grid_height, metric (optional; the game's config,
+ *   defaulting to the contract's GameConfig::default())
  *
  * Returns: { distance, proofHex, publicInputsHex }
  */
 async function provePing(input) {
-  const distance = wrappedManhattan(
+  const rules = resolveRules(input);
+  const distance = computeDistance(
     input.ping_x,
     input.ping_y,
     input.drop_x,
-    input.drop_y
+    input.drop_y,
+    rules
   );
 
   // Reduce salts mod BN254_FR to get valid field elements.
@@ -156,12 +200,17 @@ async function provePing(input) {
     drop_y: String(input.drop_y),
     drop_salt: toFieldHex(dropSaltBigInt),
     // Public inputs
+    public_inputs_version: String(PUBLIC_INPUTS_VERSION),
     session_id: String(input.session_id),
     turn: String(input.turn),
     ping_x: String(input.ping_x),
     ping_y: String(input.ping_y),
     expected_commitment: "0x" + input.drop_commitment_hex,
     expected_distance: String(distance),
+    toroidal: rules.toroidal,
+    grid_width: String(rules.grid_width),
+    grid_height: String(rules.grid_height),
+    metric: String(rules.metric),
   };
 
   console.log(
@@ -271,20 +320,15 @@ async function selfCheckProverArtifacts() {
   console.log("[prover] Running startup artifact self-check...");
   const result = await provePing({ ...sample, drop_commitment_hex });
   const decoded = decodePublicInputsSemantics(result.publicInputsHex);
-  console.log("[prover] Startup artifact self-check passed", {
-    session_id: decoded.session_id,
-    turn: decoded.turn,
-    ping_x: decoded.ping_x,
-    ping_y: decoded.ping_y,
-    drop_commitment_hex: decoded.drop_commitment_hex,
-    expected_distance: decoded.expected_distance,
-  });
+  console.log("[prover] Startup artifact self-check passed", decoded);
   return true;
 }
 
 module.exports = {
   provePing,
   computeDropCommitment,
+  computeDistance,
+  DEFAULT_RULES,
   selfCheckProverArtifacts,
   // exported for debugging/tests
   decodePublicInputsSemantics,
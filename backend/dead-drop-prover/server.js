@@ -2,12 +2,15 @@ const http = require("node:http");
 const { createHash, randomBytes } = require("node:crypto");
 const { WebSocketServer } = require("ws");
 const { Keypair, TransactionBuilder, BASE_FEE, Networks, Operation, xdr } = require("@stellar/stellar-sdk");
-const { provePing, computeDropCommitment } = require("./prover");
+const { provePing, computeDropCommitment, DEFAULT_RULES } = require("./prover");
 const { submitSorobanViaRelayer, getRpcServer, normalizeBase64, tryExtractHostFunction } = require("./relayer");
 const { EventIndexer } = require("./eventIndexer");
 const { GameStateService } = require("./gameStateService");
 
-const PING_GRID_SIZE = 100;
+const PING_GRID_SIZE = DEFAULT_RULES.grid_width;
+// Must match MIN_GRID_SIZE / MAX_GRID_SIZE in contracts/dead-drop/src/lib.rs.
+const MIN_GRID_SIZE = 10;
+const MAX_GRID_SIZE = 256;
 const RELAY_TTL_MS = Number(process.env.DEAD_DROP_RELAY_TTL_MS || 120_000);
 const RELAY_READY_GRACE_MS = Number(
   process.env.DEAD_DROP_RELAY_READY_GRACE_MS || 60_000
@@ -99,6 +102,36 @@ function parseU32(value, label) {
   return n;
 }
 
+function parseGridSize(value, fallback, label) {
+  if (value === undefined || value === null) return fallback;
+  const n = parseU32(value, label);
+  if (n < MIN_GRID_SIZE || n > MAX_GRID_SIZE) {
+    throw new Error(`${label} must be within [${MIN_GRID_SIZE}, ${MAX_GRID_SIZE}]`);
+  }
+  return n;
+}
+
+// Board rules from the game's on-chain config; anything omitted falls back to
+// the contract's GameConfig::default().
+function parseRules(body) {
+  const toroidal = body.toroidal ?? DEFAULT_RULES.toroidal;
+  if (typeof toroidal !== "boolean") {
+    throw new Error("toroidal must be a boolean");
+  }
+  const metric = body.metric === undefined || body.metric === null
+    ? DEFAULT_RULES.metric
+    : parseU32(body.metric, "metric");
+  if (metric > 2) {
+    throw new Error("metric must be 0 (manhattan), 1 (chebyshev) or 2 (euclidean squared)");
+  }
+  return {
+    toroidal,
+    grid_width: parseGridSize(body.grid_width, DEFAULT_RULES.grid_width, "grid_width"),
+    grid_height: parseGridSize(body.grid_height, DEFAULT_RULES.grid_height, "grid_height"),
+    metric,
+  };
+}
+
 function extractSessionIdFromHostFunctionXdr(funcXdrBase64) {
   try {
     if (typeof funcXdrBase64 !== "string" || !funcXdrBase64) return null;
@@ -222,13 +255,13 @@ function computeRandomnessOutputHex(sessionId, dropCommitmentHex, randomnessSign
   return digest.digest("hex");
 }
 
-function createHiddenDropArtifacts(sessionId) {
+function createHiddenDropArtifacts(sessionId, gridWidth, gridHeight) {
   const drop_x = FIXED_DROP_COORDINATE
-    ? FIXED_DROP_COORDINATE.x
-    : randomBytes(2).readUInt16BE(0) % PING_GRID_SIZE;
+    ? FIXED_DROP_COORDINATE.x % gridWidth
+    : randomBytes(2).readUInt16BE(0) % gridWidth;
   const drop_y = FIXED_DROP_COORDINATE
-    ? FIXED_DROP_COORDINATE.y
-    : randomBytes(2).readUInt16BE(0) % PING_GRID_SIZE;
+    ? FIXED_DROP_COORDINATE.y % gridHeight
+    : randomBytes(2).readUInt16BE(0) % gridHeight;
   const drop_salt_hex = randomBytes(32).toString("hex");
   const drop_commitment_hex = computeDropCommitment(drop_x, drop_y, drop_salt_hex);
   const randomness_signature_hex = randomBytes(64).toString("hex");
@@ -240,6 +273,8 @@ function createHiddenDropArtifacts(sessionId) {
 
   return {
     session_id: sessionId,
+    grid_width: gridWidth,
+    grid_height: gridHeight,
     drop_x,
     drop_y,
     drop_salt_hex,
@@ -325,8 +360,12 @@ function createServer(options = {}) {
     }
 
     let sessionId;
+    let gridWidth;
+    let gridHeight;
     try {
       sessionId = parseU32(body.session_id, "session_id");
+      gridWidth = parseGridSize(body.grid_width, DEFAULT_RULES.grid_width, "grid_width");
+      gridHeight = parseGridSize(body.grid_height, DEFAULT_RULES.grid_height, "grid_height");
     } catch (err) {
       sendError(res, 400, err.message);
       return;
@@ -334,7 +373,7 @@ function createServer(options = {}) {
 
     let entry = hiddenDropBySession.get(sessionId);
     if (!entry) {
-      entry = createHiddenDropArtifacts(sessionId);
+      entry = createHiddenDropArtifacts(sessionId, gridWidth, gridHeight);
       hiddenDropBySession.set(sessionId, entry);
     }
 
@@ -362,14 +401,19 @@ function createServer(options = {}) {
         turn: parseU32(body.turn, "turn"),
         ping_x: parseU32(body.ping_x, "ping_x"),
         ping_y: parseU32(body.ping_y, "ping_y"),
+        ...parseRules(body),
       };
     } catch (err) {
       sendError(res, 400, err.message);
       return;
     }
 
-    if (input.ping_x >= PING_GRID_SIZE || input.ping_y >= PING_GRID_SIZE) {
-      sendError(res, 400, `ping coordinates must be in [0, ${PING_GRID_SIZE - 1}]`);
+    if (input.ping_x >= input.grid_width || input.ping_y >= input.grid_height) {
+      sendError(
+        res,
+        400,
+        `ping coordinates must be within a ${input.grid_width}x${input.grid_height} grid`
+      );
       return;
     }
 
@@ -383,6 +427,19 @@ function createServer(options = {}) {
       return;
     }
 
+    if (
+      hiddenDrop.grid_width !== input.grid_width ||
+      hiddenDrop.grid_height !== input.grid_height
+    ) {
+      sendError(
+        res,
+        409,
+        `grid ${input.grid_width}x${input.grid_height} does not match the ` +
+          `${hiddenDrop.grid_width}x${hiddenDrop.grid_height} grid the drop was placed on`
+      );
+      return;
+    }
+
     try {
       const proof = await provePing({
         ...input,
@@ -26,12 +26,17 @@ cat > "$OUT_DIR/test_input.json" << 'EOF'
   "drop_x": "__TEST_DROP_X__",
   "drop_y": "__TEST_DROP_Y__",
   "drop_salt": "__TEST_DROP_SALT__",
+  "public_inputs_version": "1",
   "session_id": "1",
   "turn": "1",
   "ping_x": "50",
   "ping_y": "50",
   "expected_commitment": "__TEST_COMMITMENT__",
-  "expected_distance": "0"
+  "expected_distance": "0",
+  "toroidal": true,
+  "grid_width": "100",
+  "grid_height": "100",
+  "metric": "0"
 }
 EOF
 sed -i.bak \
@@ -54,12 +59,17 @@ $NOIR_CLI interop \
 drop_x = "50"
 drop_y = "50"
 drop_salt = "0x0000000000000000000000000000000000000000000000000000000000000001"
+public_inputs_version = "1"
 session_id = "1"
 turn = "1"
 ping_x = "50"
 ping_y = "50"
 expected_commitment = "0x0"
 expected_distance = "0"
+toroidal = true
+grid_width = "100"
+grid_height = "100"
+metric = "0"
 EOF
     nargo execute witness 2>&1 | grep -A5 "Failed constraint" || true
     echo "⚠️  Skipping witness validation for trusted setup (not needed for zkey generation)"
@@ -1,16 +1,21 @@
 #![no_std]
+// Entry points take one argument per contract parameter, and `#[contractimpl]`
+// generates matching client and args helpers outside the impl block.
+#![allow(clippy::too_many_arguments)]
 
 //! # Dead Drop – 1v1 ZK Scavenger Hunt
 //!
-//! Two players compete to find a hidden drop location on a 100×100 toroidal grid.
+//! Two players compete to find a hidden drop location on a grid
+//! (100×100 and toroidal by default; size, wrapping and turn count are per-game).
 //! The hidden drop commitment is fixed at game start using a verifier-backed
 //! randomness attestation. Players alternate submitting pings; each ping includes
 //! exact public coordinates and a ZK proof that the reported distance is correct
 //! for the hidden committed drop.
 
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype,
-    vec, Address, Bytes, BytesN, Env, IntoVal, InvokeError, Symbol, Val, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, vec,
+    xdr::{ScErrorType, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, InvokeError, Symbol, Val, Vec,
 };
 
 // ============================================================================
@@ -30,6 +35,21 @@ pub trait GameHub {
     );
 
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    // Optional extensions. The reference hub only implements `start_game` and `end_game`,
+    // so these are always called with `try_` and fall back when the hub lacks them.
+
+    fn end_game_draw(env: Env, session_id: u32);
+
+    fn end_game_with_stakes(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+        winner_points: i128,
+        loser_points: i128,
+    );
+
+    fn refund_game(env: Env, session_id: u32);
 }
 
 // ============================================================================
@@ -64,6 +84,7 @@ pub enum Error {
     InvalidTurn = 7,
     InvalidPublicInputs = 8,
     // 9 reserved (was InvalidJournalHash)
+    /// The proof verifier returned a contract error: the proof is invalid.
     ProofVerificationFailed = 10,
     TimeoutNotReached = 11,
     InvalidDistance = 12,
@@ -71,7 +92,22 @@ pub enum Error {
     LobbyNotFound = 14,
     LobbyAlreadyExists = 15,
     SelfPlay = 16,
+    /// The randomness verifier did not return `true`, for whatever reason (rejection,
+    /// contract error, trap or missing verifier).
     RandomnessVerificationFailed = 17,
+    DistanceInputMismatch = 18,
+    PotTooLarge = 19,
+    RetractNotAllowed = 20,
+    /// The proof verifier gave no answer: it trapped, or the address or function is
+    /// missing. The proof may still be invalid if the verifier rejects by panicking.
+    VerifierCallFailed = 21,
+    NotInvited = 22,
+    LobbyExpired = 23,
+    Paused = 24,
+    ResumeHintTooLarge = 25,
+    PointsTooLarge = 26,
+    /// The hub's `refund_game` returned a contract error, so the game was not aborted.
+    RefundRejected = 27,
 }
 
 // ============================================================================
@@ -86,6 +122,141 @@ pub enum GameStatus {
     Active = 1,
     Completed = 2,
     Timeout = 3,
+    /// A best-of-N round finished; waiting for `next_round` to supply a new drop.
+    RoundOver = 4,
+    /// Max turns reached with equal best distances; no winner.
+    Draw = 5,
+    /// Unwound by the admin; stakes were refunded and there is no winner.
+    Aborted = 6,
+}
+
+/// Why a game was won, recorded on `Game::win_reason` as its `u32` value.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum WinReason {
+    FoundDrop = 0,
+    ClosestAtMaxTurns = 1,
+    Timeout = 2,
+    Concede = 3,
+    /// The loser's proof was rejected while strict proofs were on.
+    Forfeit = 4,
+}
+
+/// How a game ended, returned by `get_outcome`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// Still being played, including between best-of-N rounds.
+    InProgress,
+    Win(Address, WinReason),
+    Draw,
+    /// Unwound by the admin with stakes refunded.
+    Aborted,
+}
+
+/// Per-game rule options chosen at creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    /// Whether the board wraps at the edges (torus) or is bounded.
+    /// Bound into the proof's public inputs so the circuit uses the same metric.
+    pub toroidal: bool,
+    /// Pings a player must have made before finding the drop ends the game (0 = no minimum).
+    /// An earlier distance-0 ping still records a best of 0 and wins once the threshold is met.
+    pub min_pings_before_win: u32,
+    /// Casual mode: the last pinger may retract their ping until the opponent moves.
+    pub retracts_allowed: bool,
+    /// Distance metric: `METRIC_MANHATTAN` (`dx + dy`), `METRIC_CHEBYSHEV` (`max(dx, dy)`)
+    /// or `METRIC_EUCLIDEAN_SQ` (`dx*dx + dy*dy`).
+    /// Bound into the proof's public inputs.
+    pub metric: u32,
+    /// Board width along x (10..=256). Bound into the proof's public inputs.
+    pub grid_width: u32,
+    /// Board height along y (10..=256). Bound into the proof's public inputs.
+    pub grid_height: u32,
+    /// Total turns across both players before the closest ping wins (even, <= 100).
+    pub max_turns: u32,
+    /// Pick the first pinger from `sha256(randomness_output || session_id)` instead of
+    /// always letting player1 open.
+    pub coin_flip_first_mover: bool,
+    /// Rounds a player must win to take the match (1 = single game, 2 = best-of-3, ...).
+    pub rounds_to_win: u32,
+    /// Shot clock: ledgers the player to move has before the opponent may claim a timeout.
+    pub turn_timeout_ledgers: u32,
+    /// How max-turns games are decided: `SCORING_BEST` (closest single ping) or
+    /// `SCORING_TOTAL` (lowest sum of all ping distances).
+    pub scoring_mode: u32,
+    /// Added to each seat's score when max turns decide the game (at most the max
+    /// distance). Finding the drop still wins outright.
+    pub player1_handicap: u32,
+    pub player2_handicap: u32,
+    /// Award equal max-turns scores to player1 instead of ending the game as a `Draw`.
+    pub player1_wins_ties: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            toroidal: true,
+            metric: METRIC_MANHATTAN,
+            min_pings_before_win: 0,
+            retracts_allowed: false,
+            grid_width: DEFAULT_GRID_SIZE,
+            grid_height: DEFAULT_GRID_SIZE,
+            max_turns: DEFAULT_MAX_TURNS,
+            coin_flip_first_mover: false,
+            rounds_to_win: 1,
+            turn_timeout_ledgers: TIMEOUT_LEDGERS,
+            scoring_mode: SCORING_BEST,
+            player1_handicap: 0,
+            player2_handicap: 0,
+            player1_wins_ties: false,
+        }
+    }
+}
+
+/// Undo information for the most recent ping, used by `retract_last_ping`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LastPing {
+    pub pinger: u32, // 0 = nothing to retract, 1 = player1, 2 = player2
+    pub previous_best: u32,
+    pub ledger: u32,
+}
+
+/// Whatever currently occupies a session slot, returned by `get_session`.
+// Contract types cannot box variants, so the size difference is inherent.
+#[allow(clippy::large_enum_variant)]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionState {
+    None,
+    Lobby(Lobby),
+    Game(Game),
+}
+
+/// Lifetime record for a player across every session of this contract.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    /// Losses where the player was timed out (also counted in `losses`).
+    pub timeouts: u32,
+}
+
+/// One verified ping, as stored in the on-chain turn history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PingRecord {
+    pub turn: u32,
+    pub player_is_one: bool,
+    pub distance: u32,
+    pub x: u32,
+    pub y: u32,
+    pub ledger: u32,
 }
 
 #[contracttype]
@@ -99,6 +270,41 @@ pub struct Game {
     pub status: GameStatus,
     pub current_turn: u32,
     pub whose_turn: u32, // 1 = player1 pings, 2 = player2 pings
+    /// Who pings on even turns this round (1 or 2); `whose_turn` must agree with `current_turn`'s parity.
+    pub round_opener: u32,
+    pub player1_best_distance: u32,
+    pub player2_best_distance: u32,
+    pub player1_pings: u32,
+    pub player2_pings: u32,
+    /// Sum of every ping distance this round; decides `SCORING_TOTAL` games.
+    pub player1_total_distance: u32,
+    pub player2_total_distance: u32,
+    pub last_ping: LastPing,
+    /// Turn undone by the last retract this round; it cannot be retracted again.
+    pub retracted_turn: Option<u32>,
+    pub player1_rounds: u32,
+    pub player2_rounds: u32,
+    pub winner: Option<Address>,
+    /// `WinReason` as `u32`, set together with `winner`.
+    pub win_reason: Option<u32>,
+    pub last_action_ledger: u32,
+    pub config: GameConfig,
+    /// SHA-256 over the XDR-encoded `config`, so both clients can confirm the ruleset.
+    pub rules_hash: BytesN<32>,
+}
+
+/// The pre-`GameV2` game layout, as still stored under the legacy `Game(session_id)` key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyGame {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub drop_commitment: BytesN<32>,
+    pub status: GameStatus,
+    pub current_turn: u32,
+    pub whose_turn: u32,
     pub player1_best_distance: u32,
     pub player2_best_distance: u32,
     pub winner: Option<Address>,
@@ -111,17 +317,89 @@ pub struct Lobby {
     pub host: Address,
     pub host_points: i128,
     pub created_ledger: u32,
+    pub config: GameConfig,
+    /// Private match: only this address may join. `None` lets anyone with the room code in.
+    pub expected_opponent: Option<Address>,
+}
+
+/// The pre-`LobbyV2` lobby layout, as still stored under the legacy `Lobby(session_id)` key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyLobby {
+    pub host: Address,
+    pub host_points: i128,
+    pub created_ledger: u32,
+}
+
+/// The fields a list view needs, without commitments or stakes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSummary {
+    pub status: GameStatus,
+    pub current_turn: u32,
+    pub whose_turn: u32,
+    pub winner: Option<Address>,
+    pub player1_best_distance: u32,
+    pub player2_best_distance: u32,
+}
+
+/// Everything a client needs to render a game's exact variant in one read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleSet {
+    pub grid_width: u32,
+    pub grid_height: u32,
+    /// `METRIC_MANHATTAN`, `METRIC_CHEBYSHEV` or `METRIC_EUCLIDEAN_SQ`.
+    pub metric: u32,
+    pub max_turns: u32,
+    pub timeout_ledgers: u32,
+    pub min_pings_before_win: u32,
+    pub toroidal: bool,
+    pub retracts_allowed: bool,
+    pub coin_flip_first_mover: bool,
+    pub rounds_to_win: u32,
+    pub scoring_mode: u32,
+    pub player1_handicap: u32,
+    pub player2_handicap: u32,
+    pub player1_wins_ties: bool,
+    pub rules_hash: BytesN<32>,
+}
+
+/// What this deployment supports, so clients can feature-detect before starting a game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capabilities {
+    pub max_grid_size: u32,
+    /// Distance metric ids accepted in `GameConfig` (`METRIC_MANHATTAN`, `METRIC_CHEBYSHEV`,
+    /// `METRIC_EUCLIDEAN_SQ`).
+    pub supported_metrics: Bytes,
+    pub supports_risc0: bool,
+    pub supports_ultrahonk: bool,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
+    /// Legacy game key; read as a fallback and migrated to `GameV2` on next write.
     Game(u32),
     Lobby(u32),
     GameHubAddress,
     Admin,
     VerifierId,
     RandomnessVerifierId,
+    FairnessProof(u32),
+    MaxPot,
+    PlayerGames(Address),
+    GameV2(u32),
+    LobbyV2(u32),
+    PingLog(u32),
+    Stats(Address),
+    OpenLobbies,
+    StrictProofs,
+    PublicInputsVersion,
+    Paused,
+    /// Opaque client blob for reconnecting to a session; never interpreted on-chain.
+    ResumeHint(u32, Address),
 }
 
 // ============================================================================
@@ -131,24 +409,78 @@ pub enum DataKey {
 /// 30-day TTL in ledgers (~5 seconds per ledger)
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
-/// Maximum number of turns (each player gets 15 pings)
-const MAX_TURNS: u32 = 30;
+/// ~180-day TTL for persistent player stats, refreshed on every update.
+const STATS_TTL_LEDGERS: u32 = 3_110_400;
+
+/// Default number of turns (each player gets 15 pings)
+const DEFAULT_MAX_TURNS: u32 = 30;
+
+/// Upper bound on configurable turns per game.
+const MAX_TURNS_LIMIT: u32 = 100;
+
+/// Default grid dimensions for coordinate bounds checks.
+const DEFAULT_GRID_SIZE: u32 = 100;
 
-/// Grid dimensions for coordinate bounds checks.
-const GRID_SIZE: u32 = 100;
+/// Bounds on each configurable board dimension.
+const MIN_GRID_SIZE: u32 = 10;
+const MAX_GRID_SIZE: u32 = 256;
 
-/// Max wrapped Manhattan distance on a 100x100 toroidal grid.
-const MAX_DISTANCE: u32 = 100;
+/// Upper bound on `rounds_to_win` (best-of-9).
+const MAX_ROUNDS_TO_WIN: u32 = 5;
 
-/// Timeout threshold in ledgers (~50 minutes = 600 ledgers)
+/// Distance metric ids for `GameConfig::metric`.
+pub const METRIC_MANHATTAN: u32 = 0;
+pub const METRIC_CHEBYSHEV: u32 = 1;
+/// Squared Euclidean distance; reported distances are `dx*dx + dy*dy`.
+pub const METRIC_EUCLIDEAN_SQ: u32 = 2;
+
+/// Scoring modes for `GameConfig::scoring_mode`.
+pub const SCORING_BEST: u32 = 0;
+pub const SCORING_TOTAL: u32 = 1;
+
+/// Default per-turn timeout in ledgers (~50 minutes = 600 ledgers)
 const TIMEOUT_LEDGERS: u32 = 600;
 
+/// Bounds on a configurable shot clock (~1 minute to ~1 day).
+const MIN_TURN_TIMEOUT_LEDGERS: u32 = 12;
+const MAX_TURN_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Window in which a ping can be retracted (~1 minute = 12 ledgers)
+const RETRACT_WINDOW_LEDGERS: u32 = 12;
+
+/// Maximum number of lobbies listed by `get_open_lobbies`.
+const MAX_OPEN_LOBBIES: u32 = 256;
+
+/// Ledgers an unjoined lobby stays joinable (~10 hours).
+const LOBBY_TTL_LEDGERS: u32 = 7_200;
+
+/// Number of most recent sessions remembered per player.
+const MAX_PLAYER_GAMES: u32 = 16;
+
+/// Largest stake a single player may commit, leaving the hub headroom to sum and scale pots.
+const MAX_POINTS: i128 = i128::MAX / 4;
+
+/// Largest resume hint a player may store per session.
+const MAX_RESUME_HINT_LEN: u32 = 256;
+
 /// Sentinel value for "no distance recorded yet"
 const NO_DISTANCE: u32 = u32::MAX;
 
-/// Number of public inputs expected from the Noir circuit.
-/// [session_id, turn, ping_x, ping_y, drop_commitment, expected_distance]
-const NUM_PUBLIC_INPUTS: usize = 6;
+/// Byte length of a stored fairness proof:
+/// randomness_output (32) || drop_commitment (32) || randomness_signature (64)
+const FAIRNESS_PROOF_LEN: u32 = 128;
+
+/// Number of public inputs expected from the Noir circuit. The layout is fixed by the
+/// index constants below, so a circuit with a different count needs a new contract.
+/// [version, session_id, turn, ping_x, ping_y, drop_commitment, expected_distance, toroidal, grid_width, grid_height, metric]
+const NUM_PUBLIC_INPUTS: usize = 11;
+
+/// Position of `expected_distance` within the public inputs.
+const DISTANCE_INPUT_INDEX: u32 = 6;
+
+/// Default public-input layout version; the circuit asserts the same constant.
+/// The admin bumps it with `set_public_inputs_version` when the circuit changes.
+const PUBLIC_INPUTS_VERSION: u32 = 1;
 
 // ============================================================================
 // Contract
@@ -192,11 +524,14 @@ impl DeadDropContract {
         randomness_output: BytesN<32>,
         drop_commitment: BytesN<32>,
         randomness_signature: BytesN<64>,
+        config: GameConfig,
     ) -> Result<(), Error> {
-        // Points must be positive.
-        if player1_points <= 0 || player2_points <= 0 {
-            return Err(Error::InvalidDistance);
-        }
+        check_not_paused(&env)?;
+        // Points must be positive and bounded.
+        check_points(player1_points)?;
+        check_points(player2_points)?;
+        validate_config(&config)?;
+        check_pot(&env, player1_points, player2_points)?;
 
         // Prevent self-play
         if player1 == player2 {
@@ -204,12 +539,10 @@ impl DeadDropContract {
         }
 
         // Reject if session slot is already in use.
-        let game_key = DataKey::Game(session_id);
-        if env.storage().temporary().has(&game_key) {
+        if game_exists(&env, session_id) {
             return Err(Error::LobbyAlreadyExists);
         }
-        let lobby_key = DataKey::Lobby(session_id);
-        if env.storage().temporary().has(&lobby_key) {
+        if load_lobby(&env, session_id).is_some() {
             return Err(Error::LobbyAlreadyExists);
         }
 
@@ -252,33 +585,38 @@ impl DeadDropContract {
             &player2_points,
         );
 
-        let game = Game {
+        store_fairness_proof(
+            &env,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        );
+
+        let opener = first_mover(&env, session_id, &randomness_output, &config);
+        let mut game = new_game(
+            &env,
             player1,
             player2,
-            player1_points,
-            player2_points,
+            (player1_points, player2_points),
             drop_commitment,
-            status: GameStatus::Active,
-            current_turn: 0,
-            whose_turn: 1,
-            player1_best_distance: NO_DISTANCE,
-            player2_best_distance: NO_DISTANCE,
-            winner: None,
-            last_action_ledger: env.ledger().sequence(),
-        };
+            opener,
+            config,
+        );
+        set_status(&env, session_id, &mut game, GameStatus::Active);
 
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        save_game(&env, session_id, &game);
+        index_player_game(&env, &game.player1, session_id);
+        index_player_game(&env, &game.player2, session_id);
+        emit_game_start(&env, session_id, &game);
 
         Ok(())
     }
 
     /// Submit a ping result with ZK proof verification (Noir + UltraHonk).
     ///
-    /// Public inputs layout (6 x 32-byte big-endian field elements):
-    /// [session_id, turn, ping_x, ping_y, drop_commitment, expected_distance]
+    /// Public inputs layout (11 x 32-byte big-endian field elements):
+    /// [version, session_id, turn, ping_x, ping_y, drop_commitment, expected_distance, toroidal, grid_width, grid_height, metric]
     pub fn submit_ping(
         env: Env,
         session_id: u32,
@@ -292,12 +630,7 @@ impl DeadDropContract {
     ) -> Result<Option<Address>, Error> {
         player.require_auth();
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
 
         if game.winner.is_some() {
             return Err(Error::GameAlreadyEnded);
@@ -305,18 +638,22 @@ impl DeadDropContract {
         if game.status != GameStatus::Active {
             return Err(Error::InvalidGameStatus);
         }
-        if ping_x >= GRID_SIZE || ping_y >= GRID_SIZE {
+        if ping_x >= game.config.grid_width || ping_y >= game.config.grid_height {
             return Err(Error::InvalidDistance);
         }
-        if distance > MAX_DISTANCE {
+        if distance > max_distance(&game.config) {
             return Err(Error::InvalidDistance);
         }
         if turn != game.current_turn {
             return Err(Error::InvalidTurn);
         }
-        if game.current_turn >= MAX_TURNS {
+        if game.current_turn >= game.config.max_turns {
             return Err(Error::MaxTurnsReached);
         }
+        // The opener pings on even turns; a mismatch means whose_turn and current_turn desynced.
+        if (turn % 2 == 0) != (game.whose_turn == game.round_opener) {
+            return Err(Error::InvalidTurn);
+        }
 
         // Determine who is pinging and validate it's their turn
         let is_player1_turn = game.whose_turn == 1;
@@ -337,22 +674,35 @@ impl DeadDropContract {
             return Err(Error::InvalidPublicInputs);
         }
 
+        // Surface a precise error when the claimed distance and the proven one disagree.
+        let proven_distance = public_inputs
+            .get(DISTANCE_INPUT_INDEX)
+            .ok_or(Error::InvalidPublicInputs)?;
+        if proven_distance != u32_to_field_bytes(&env, distance) {
+            return Err(Error::DistanceInputMismatch);
+        }
+
         // Reconstruct expected public inputs from on-chain state and submitted params.
+        let version: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PublicInputsVersion)
+            .unwrap_or(PUBLIC_INPUTS_VERSION);
         let expected_inputs = build_public_inputs(
             &env,
+            version,
             session_id,
             turn,
             ping_x,
             ping_y,
             &game.drop_commitment,
             distance,
+            &game.config,
         );
 
         // Compare submitted public inputs against expected values
-        for i in 0..NUM_PUBLIC_INPUTS {
-            let submitted = public_inputs.get(i as u32).unwrap();
-            let expected = expected_inputs.get(i as u32).unwrap();
-            if submitted != expected {
+        for (i, expected) in expected_inputs.iter().enumerate() {
+            if public_inputs.get(i as u32) != Some(expected) {
                 return Err(Error::InvalidPublicInputs);
             }
         }
@@ -364,185 +714,668 @@ impl DeadDropContract {
             .get(&DataKey::VerifierId)
             .expect("VerifierId not set");
 
-        verify_proof(&env, &verifier_addr, &proof, &public_inputs)?;
+        if let Err(err) = verify_proof(&env, &verifier_addr, &proof, &public_inputs) {
+            // In strict mode a rejected proof forfeits the game instead of allowing a
+            // free retry. Verifier call failures are never the player's fault.
+            let strict: bool = env
+                .storage()
+                .instance()
+                .get(&DataKey::StrictProofs)
+                .unwrap_or(false);
+            if !strict || err != Error::ProofVerificationFailed {
+                return Err(err);
+            }
 
-        // Emit ping event for frontend syncing
-        // Topic: ["ping", session_id]
-        // Data: [player, turn, distance, ping_x, ping_y]
-        env.events().publish(
-            (Symbol::new(&env, "ping"), session_id),
-            (player.clone(), turn, distance, ping_x, ping_y),
+            let winner = if is_player1_turn {
+                game.player2.clone()
+            } else {
+                game.player1.clone()
+            };
+            game.winner = Some(winner.clone());
+            game.win_reason = Some(WinReason::Forfeit as u32);
+            set_status(&env, session_id, &mut game, GameStatus::Completed);
+            game.last_action_ledger = env.ledger().sequence();
+            save_game(&env, session_id, &game);
+
+            // Topic: ["forfeit", session_id]
+            // Data: [player, turn]
+            publish_event(&env, "forfeit", session_id, (player, turn));
+
+            report_game_end(&env, session_id, &game, &winner);
+            return Ok(Some(winner));
+        }
+
+        append_ping_log(
+            &env,
+            session_id,
+            PingRecord {
+                turn,
+                player_is_one: is_player1_turn,
+                distance,
+                x: ping_x,
+                y: ping_y,
+                ledger: env.ledger().sequence(),
+            },
+            game.config.max_turns,
         );
 
         // Record distance and update best
+        let previous_best = if is_player1_turn {
+            game.player1_best_distance
+        } else {
+            game.player2_best_distance
+        };
         if is_player1_turn {
+            game.player1_pings += 1;
+            game.player1_total_distance += distance;
             if distance < game.player1_best_distance {
                 game.player1_best_distance = distance;
             }
-        } else if distance < game.player2_best_distance {
-            game.player2_best_distance = distance;
+        } else {
+            game.player2_pings += 1;
+            game.player2_total_distance += distance;
+            if distance < game.player2_best_distance {
+                game.player2_best_distance = distance;
+            }
         }
 
-        // Check for immediate win (distance == 0 means found the drop)
-        if distance == 0 {
-            let winner = pinger.clone();
-            game.winner = Some(winner.clone());
-            game.status = GameStatus::Completed;
-            game.last_action_ledger = env.ledger().sequence();
-
-            env.storage().temporary().set(&key, &game);
-            env.storage()
-                .temporary()
-                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        // Settle the round before announcing the ping, so the event carries the turn
+        // that follows it. A best distance of 0 means the drop was found, once the pinger
+        // has made enough pings for the game's threshold; otherwise the round is decided
+        // by distance when this was the last turn.
+        let (pinger_best, pinger_pings) = if is_player1_turn {
+            (game.player1_best_distance, game.player1_pings)
+        } else {
+            (game.player2_best_distance, game.player2_pings)
+        };
+        let round_result = if pinger_best == 0 && pinger_pings >= game.config.min_pings_before_win {
+            Some((Some(pinger.clone()), WinReason::FoundDrop))
+        } else if game.current_turn + 1 >= game.config.max_turns {
+            Some((
+                Self::determine_winner_by_distance(&game),
+                WinReason::ClosestAtMaxTurns,
+            ))
+        } else {
+            None
+        };
+        // No one moves once the match is over; after a round the player who did not
+        // open it commits the next drop, as `end_round` records.
+        let next_turn = match &round_result {
+            None if is_player1_turn => 2u32,
+            None => 1u32,
+            Some((round_winner, _)) if round_ends_match(&game, round_winner.as_ref()) => 0,
+            Some(_) if game.round_opener == 1 => 2,
+            Some(_) => 1,
+        };
 
-            // Report to Game Hub
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
-            let player1_won = winner == game.player1;
-            game_hub.end_game(&session_id, &player1_won);
+        // Emit ping event for frontend syncing
+        // Topic: ["ping", session_id]
+        // Data: [player, turn, distance, ping_x, ping_y, pinger_best_distance, whose_turn]
+        publish_event(
+            &env,
+            "ping",
+            session_id,
+            (
+                player.clone(),
+                turn,
+                distance,
+                ping_x,
+                ping_y,
+                pinger_best,
+                next_turn,
+            ),
+        );
+        if distance < previous_best {
+            // Topic: ["new_best", session_id]
+            // Data: [player, new_best_distance]
+            publish_event(&env, "new_best", session_id, (player.clone(), distance));
+        }
 
-            return Ok(Some(winner));
+        game.last_action_ledger = env.ledger().sequence();
+        if let Some((round_winner, WinReason::FoundDrop)) = round_result {
+            return Ok(end_round(
+                &env,
+                session_id,
+                &mut game,
+                round_winner,
+                WinReason::FoundDrop,
+            ));
         }
 
         // Advance turn
         game.current_turn += 1;
         game.whose_turn = if is_player1_turn { 2 } else { 1 };
-        game.last_action_ledger = env.ledger().sequence();
-
-        // Check if max turns reached → determine winner by best distance
-        if game.current_turn >= MAX_TURNS {
-            let winner = Self::determine_winner_by_distance(&game);
-            game.winner = Some(winner.clone());
-            game.status = GameStatus::Completed;
-
-            env.storage().temporary().set(&key, &game);
-            env.storage()
-                .temporary()
-                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-            // Report to Game Hub
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
-            let player1_won = winner == game.player1;
-            game_hub.end_game(&session_id, &player1_won);
+        game.last_ping = LastPing {
+            pinger: if is_player1_turn { 1 } else { 2 },
+            previous_best,
+            ledger: game.last_action_ledger,
+        };
 
-            return Ok(Some(winner));
+        // Max turns reached → the round goes to the best distance found above
+        if let Some((round_winner, reason)) = round_result {
+            return Ok(end_round(&env, session_id, &mut game, round_winner, reason));
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        save_game(&env, session_id, &game);
 
         Ok(None)
     }
 
-    /// Force a timeout win if the opponent has been AFK.
-    pub fn force_timeout(
+    /// Start the next round of a best-of-N match with a freshly committed drop.
+    ///
+    /// The randomness artifacts are verified exactly as at game start, and the
+    /// previous round's commitment may not be reused. Coin-flip games draw the
+    /// round's opener from the new randomness output. Only the player who did not
+    /// open the finished round (`whose_turn` while `RoundOver`) may call it.
+    pub fn next_round(
         env: Env,
         session_id: u32,
         player: Address,
-    ) -> Result<Address, Error> {
+        randomness_output: BytesN<32>,
+        drop_commitment: BytesN<32>,
+        randomness_signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.status != GameStatus::RoundOver {
+            return Err(Error::InvalidGameStatus);
+        }
+        let expected_player = if game.whose_turn == 1 {
+            &game.player1
+        } else {
+            &game.player2
+        };
+        if player != *expected_player {
+            return Err(Error::NotYourTurn);
+        }
         player.require_auth();
+        if drop_commitment == game.drop_commitment {
+            return Err(Error::RandomnessVerificationFailed);
+        }
 
-        let key = DataKey::Game(session_id);
-        let mut game: Game = env
+        let randomness_verifier_addr: Address = env
             .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+            .instance()
+            .get(&DataKey::RandomnessVerifierId)
+            .expect("RandomnessVerifierId not set");
+        verify_randomness(
+            &env,
+            &randomness_verifier_addr,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        )?;
+
+        store_fairness_proof(
+            &env,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        );
+
+        let opener = first_mover(&env, session_id, &randomness_output, &game.config);
+        game.drop_commitment = drop_commitment;
+        game.whose_turn = opener;
+        game.round_opener = opener;
+        set_status(&env, session_id, &mut game, GameStatus::Active);
+        game.last_action_ledger = env.ledger().sequence();
+
+        save_game(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Retract the caller's most recent ping in games that allow it.
+    ///
+    /// Only valid before the opponent pings and within `RETRACT_WINDOW_LEDGERS`, and at
+    /// most once per turn. Restores the turn, the pinger's ping count and their previous
+    /// best distance.
+    pub fn retract_last_ping(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
 
         if game.winner.is_some() {
             return Err(Error::GameAlreadyEnded);
         }
-
-        // Must be a participant
+        if game.status != GameStatus::Active {
+            return Err(Error::InvalidGameStatus);
+        }
         if player != game.player1 && player != game.player2 {
             return Err(Error::NotPlayer);
         }
+        if !game.config.retracts_allowed {
+            return Err(Error::RetractNotAllowed);
+        }
+
+        let last = game.last_ping.clone();
+        if last.pinger == 0 {
+            return Err(Error::RetractNotAllowed);
+        }
+        let last_pinger = if last.pinger == 1 {
+            &game.player1
+        } else {
+            &game.player2
+        };
+        if player != *last_pinger {
+            return Err(Error::NotYourTurn);
+        }
 
-        // Check timeout
         let current_ledger = env.ledger().sequence();
-        if current_ledger < game.last_action_ledger + TIMEOUT_LEDGERS {
-            return Err(Error::TimeoutNotReached);
+        if current_ledger > last.ledger.saturating_add(RETRACT_WINDOW_LEDGERS) {
+            return Err(Error::RetractNotAllowed);
+        }
+        // Otherwise a player could ping, retract and ping again to probe the drop.
+        if game.retracted_turn == Some(game.current_turn - 1) {
+            return Err(Error::RetractNotAllowed);
         }
 
-        // The player claiming timeout wins (opponent was AFK)
-        let winner = player.clone();
-        game.winner = Some(winner.clone());
-        game.status = GameStatus::Timeout;
+        let log_key = DataKey::PingLog(session_id);
+        let mut log: Vec<PingRecord> = env
+            .storage()
+            .temporary()
+            .get(&log_key)
+            .unwrap_or(Vec::new(&env));
+        let retracted_distance = log.pop_back().map(|record| record.distance).unwrap_or(0);
+        env.storage().temporary().set(&log_key, &log);
+
+        game.current_turn -= 1;
+        game.retracted_turn = Some(game.current_turn);
+        game.whose_turn = last.pinger;
+        if last.pinger == 1 {
+            game.player1_pings -= 1;
+            game.player1_total_distance -= retracted_distance;
+            game.player1_best_distance = last.previous_best;
+        } else {
+            game.player2_pings -= 1;
+            game.player2_total_distance -= retracted_distance;
+            game.player2_best_distance = last.previous_best;
+        }
+        game.last_ping.pinger = 0;
         game.last_action_ledger = current_ledger;
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        save_game(&env, session_id, &game);
 
-        // Report to Game Hub
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
+        // Topic: ["retract", session_id]
+        // Data: [player, turn]
+        publish_event(&env, "retract", session_id, (player, game.current_turn));
 
-        Ok(winner)
+        Ok(())
     }
 
-    /// Read-only game state query.
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+    /// Force a timeout win if the player to move has been AFK for the game's shot clock.
+    /// Only the waiting player may claim it. Between rounds the player who owes
+    /// `next_round` is the one on the clock.
+    pub fn force_timeout(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !matches!(game.status, GameStatus::Active | GameStatus::RoundOver) {
+            return Err(Error::InvalidGameStatus);
+        }
+
+        // Must be a participant
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        // Only the waiting player may claim; the player to move cannot win by stalling.
+        let player_to_move = if game.whose_turn == 1 {
+            &game.player1
+        } else {
+            &game.player2
+        };
+        if player == *player_to_move {
+            return Err(Error::NotYourTurn);
+        }
+
+        // Check the shot clock. A deadline past u32::MAX can never have been reached.
+        let current_ledger = env.ledger().sequence();
+        match game.last_action_ledger.checked_add(game.config.turn_timeout_ledgers) {
+            Some(deadline) if current_ledger >= deadline => {}
+            _ => return Err(Error::TimeoutNotReached),
+        }
+
+        // The player claiming timeout wins (opponent was AFK)
+        let winner = player.clone();
+        game.winner = Some(winner.clone());
+        game.win_reason = Some(WinReason::Timeout as u32);
+        set_status(&env, session_id, &mut game, GameStatus::Timeout);
+        game.last_action_ledger = current_ledger;
+
+        save_game(&env, session_id, &game);
+
+        report_game_end(&env, session_id, &game, &winner);
+
+        Ok(winner)
+    }
+
+    /// Refresh the TTL of an unfinished game's storage without touching its state,
+    /// so a long think cannot let the session expire.
+    pub fn bump_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if !matches!(game.status, GameStatus::Active | GameStatus::RoundOver) {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        extend_session_ttl(&env, session_id, &game);
+        Ok(())
+    }
+
+    /// Stash an opaque blob (typically the player's encrypted salt and coordinates) so a
+    /// reloaded client can resume without a backend. Overwrites any previous hint.
+    pub fn set_resume_hint(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hint: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if hint.len() > MAX_RESUME_HINT_LEN {
+            return Err(Error::ResumeHintTooLarge);
+        }
+
+        let key = DataKey::ResumeHint(session_id, player);
+        env.storage().temporary().set(&key, &hint);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// The player's own resume hint for a session, if one was stored.
+    pub fn get_resume_hint(env: Env, session_id: u32, player: Address) -> Option<Bytes> {
+        player.require_auth();
+        env.storage()
+            .temporary()
+            .get(&DataKey::ResumeHint(session_id, player))
+    }
+
+    /// Give up an active game. The opponent is recorded as the winner.
+    pub fn concede(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.winner.is_some() || matches!(game.status, GameStatus::Draw | GameStatus::Aborted) {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let winner = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        game.winner = Some(winner.clone());
+        game.win_reason = Some(WinReason::Concede as u32);
+        set_status(&env, session_id, &mut game, GameStatus::Completed);
+        game.last_action_ledger = env.ledger().sequence();
+
+        save_game(&env, session_id, &game);
+
+        // Topic: ["concede", session_id]
+        // Data: [player]
+        publish_event(&env, "concede", session_id, player);
+
+        report_game_end(&env, session_id, &game, &winner);
+
+        Ok(winner)
+    }
+
+    /// Replay a finished game between the same players, in the same session slot.
+    ///
+    /// Both players must authorize their stake again. Seats are swapped so the
+    /// previous loser becomes player1 and pings first; the new drop must come
+    /// with fresh randomness artifacts.
+    pub fn rematch(
+        env: Env,
+        session_id: u32,
+        requester: Address,
+        randomness_output: BytesN<32>,
+        drop_commitment: BytesN<32>,
+        randomness_signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        check_not_paused(&env)?;
+        let previous = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if !matches!(previous.status, GameStatus::Completed | GameStatus::Timeout) {
+            return Err(Error::InvalidGameStatus);
+        }
+        if requester != previous.player1 && requester != previous.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if drop_commitment == previous.drop_commitment {
+            return Err(Error::RandomnessVerificationFailed);
+        }
+
+        // The loser of the previous game takes the player1 seat and moves first.
+        let seats_swapped = previous.winner == Some(previous.player1.clone());
+        let (player1, player2, player1_points, player2_points) =
+            if seats_swapped {
+                (
+                    previous.player2.clone(),
+                    previous.player1.clone(),
+                    previous.player2_points,
+                    previous.player1_points,
+                )
+            } else {
+                (
+                    previous.player1.clone(),
+                    previous.player2.clone(),
+                    previous.player1_points,
+                    previous.player2_points,
+                )
+            };
+        check_pot(&env, player1_points, player2_points)?;
+
+        player1.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), player1_points.into_val(&env)],
+        );
+        player2.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), player2_points.into_val(&env)],
+        );
+
+        let randomness_verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::RandomnessVerifierId)
+            .expect("RandomnessVerifierId not set");
+        verify_randomness(
+            &env,
+            &randomness_verifier_addr,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        )?;
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
+
+        store_fairness_proof(
+            &env,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        );
+        env.storage()
+            .temporary()
+            .remove(&DataKey::PingLog(session_id));
+        // Hints describe the previous drop and would mislead a resuming client.
+        for player in [&previous.player1, &previous.player2] {
+            env.storage()
+                .temporary()
+                .remove(&DataKey::ResumeHint(session_id, player.clone()));
+        }
+
+        // Handicaps belong to the players, so they follow them to their new seats.
+        let mut config = previous.config;
+        if seats_swapped {
+            core::mem::swap(&mut config.player1_handicap, &mut config.player2_handicap);
+        }
+        // Without a coin flip the previous loser, now player1, opens.
+        let opener = first_mover(&env, session_id, &randomness_output, &config);
+        let mut game = new_game(
+            &env,
+            player1,
+            player2,
+            (player1_points, player2_points),
+            drop_commitment,
+            opener,
+            config,
+        );
+        set_status(&env, session_id, &mut game, GameStatus::Active);
+
+        save_game(&env, session_id, &game);
+        index_player_game(&env, &game.player1, session_id);
+        index_player_game(&env, &game.player2, session_id);
+        emit_game_start(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Read-only game state query.
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        load_game(&env, session_id).ok_or(Error::GameNotFound)
+    }
+
+    /// Lightweight view of a game for leaderboards and lobby lists.
+    pub fn get_game_summary(env: Env, session_id: u32) -> Result<GameSummary, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(GameSummary {
+            status: game.status,
+            current_turn: game.current_turn,
+            whose_turn: game.whose_turn,
+            winner: game.winner,
+            player1_best_distance: game.player1_best_distance,
+            player2_best_distance: game.player2_best_distance,
+        })
+    }
+
+    /// How the game ended, so post-game screens need not combine `status` and `winner`.
+    pub fn get_outcome(env: Env, session_id: u32) -> Result<Outcome, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(match game.status {
+            GameStatus::Created | GameStatus::Active | GameStatus::RoundOver => Outcome::InProgress,
+            GameStatus::Draw => Outcome::Draw,
+            GameStatus::Aborted => Outcome::Aborted,
+            GameStatus::Completed | GameStatus::Timeout => {
+                // A finished game without a winner (e.g. written before `Draw` existed)
+                // ended level.
+                let Some(winner) = game.winner else {
+                    return Ok(Outcome::Draw);
+                };
+                let reason = match game.win_reason {
+                    Some(0) => WinReason::FoundDrop,
+                    Some(1) => WinReason::ClosestAtMaxTurns,
+                    Some(2) => WinReason::Timeout,
+                    Some(3) => WinReason::Concede,
+                    Some(4) => WinReason::Forfeit,
+                    // Unrecorded reason: fall back to what the status alone implies.
+                    _ if game.status == GameStatus::Timeout => WinReason::Timeout,
+                    _ => WinReason::ClosestAtMaxTurns,
+                };
+                Outcome::Win(winner, reason)
+            }
+        })
+    }
+
+    /// Verified pings so far, oldest first. Holds at most `max_turns` entries;
+    /// in best-of-N matches older rounds roll off the front.
+    pub fn get_ping_log(env: Env, session_id: u32) -> Result<Vec<PingRecord>, Error> {
+        if !game_exists(&env, session_id) {
+            return Err(Error::GameNotFound);
+        }
+        Ok(env
+            .storage()
+            .temporary()
+            .get(&DataKey::PingLog(session_id))
+            .unwrap_or(Vec::new(&env)))
     }
 
     /// Open a lobby for a game session. Player 1 creates it with a room code (session_id).
-    /// This is single-sig and does not require the opponent's address.
+    /// This is single-sig and does not require the opponent's address, though the host
+    /// may pin one with `expected_opponent` for a private match.
     pub fn open_game(
         env: Env,
         session_id: u32,
         host: Address,
         host_points: i128,
+        config: GameConfig,
+        expected_opponent: Option<Address>,
     ) -> Result<(), Error> {
-        if host_points <= 0 {
-            return Err(Error::InvalidDistance);
-        }
+        check_not_paused(&env)?;
+        check_points(host_points)?;
+        validate_config(&config)?;
+        check_pot(&env, host_points, 0)?;
 
         host.require_auth_for_args(
             vec![&env, session_id.into_val(&env), host_points.into_val(&env)],
         );
 
-        // Reject if session slot is already in use
-        let lobby_key = DataKey::Lobby(session_id);
-        if env.storage().temporary().has(&lobby_key) {
-            return Err(Error::LobbyAlreadyExists);
+        // Reject if session slot is already in use; an expired lobby is simply replaced.
+        if let Some(existing) = load_lobby(&env, session_id) {
+            if !lobby_expired(&env, &existing) {
+                return Err(Error::LobbyAlreadyExists);
+            }
         }
-        let game_key = DataKey::Game(session_id);
-        if env.storage().temporary().has(&game_key) {
+        if game_exists(&env, session_id) {
             return Err(Error::LobbyAlreadyExists);
         }
 
+        list_open_lobby(&env, session_id)?;
+
         let lobby = Lobby {
             host,
             host_points,
             created_ledger: env.ledger().sequence(),
+            config,
+            expected_opponent,
         };
-        env.storage().temporary().set(&lobby_key, &lobby);
-        env.storage()
-            .temporary()
-            .extend_ttl(&lobby_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        save_lobby(&env, session_id, &lobby);
+
+        // Topic: ["lobby_open", session_id]
+        // Data: [host, host_points]
+        publish_event(&env, "lobby_open", session_id, (lobby.host, lobby.host_points));
 
         Ok(())
     }
@@ -558,121 +1391,513 @@ impl DeadDropContract {
         drop_commitment: BytesN<32>,
         randomness_signature: BytesN<64>,
     ) -> Result<(), Error> {
-        if joiner_points <= 0 {
-            return Err(Error::InvalidDistance);
-        }
+        check_not_paused(&env)?;
+        check_points(joiner_points)?;
 
         joiner.require_auth_for_args(
             vec![&env, session_id.into_val(&env), joiner_points.into_val(&env)],
         );
 
-        let lobby_key = DataKey::Lobby(session_id);
-        let lobby: Lobby = env
-            .storage()
-            .temporary()
-            .get(&lobby_key)
-            .ok_or(Error::LobbyNotFound)?;
+        let lobby = load_lobby(&env, session_id).ok_or(Error::LobbyNotFound)?;
 
+        // Failing here rolls back any cleanup, so expired lobbies are removed by
+        // `reap_lobby` or by reopening the slot.
+        if lobby_expired(&env, &lobby) {
+            return Err(Error::LobbyExpired);
+        }
         if joiner == lobby.host {
             return Err(Error::SelfPlay);
         }
+        if let Some(expected) = &lobby.expected_opponent {
+            if joiner != *expected {
+                return Err(Error::NotInvited);
+            }
+        }
+        check_pot(&env, lobby.host_points, joiner_points)?;
+
+        // Verify randomness artifacts before starting the game.
+        let randomness_verifier_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::RandomnessVerifierId)
+            .expect("RandomnessVerifierId not set");
+        verify_randomness(
+            &env,
+            &randomness_verifier_addr,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        )?;
+
+        // Consume the lobby
+        remove_lobby(&env, session_id);
+        unlist_open_lobby(&env, session_id);
+
+        // Now both players are known — call Game Hub
+        let hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &hub_addr);
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &lobby.host,
+            &joiner,
+            &lobby.host_points,
+            &joiner_points,
+        );
+
+        store_fairness_proof(
+            &env,
+            session_id,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+        );
+
+        // Create the game directly as active (no commit phase).
+        let opener = first_mover(&env, session_id, &randomness_output, &lobby.config);
+        let mut game = new_game(
+            &env,
+            lobby.host,
+            joiner,
+            (lobby.host_points, joiner_points),
+            drop_commitment,
+            opener,
+            lobby.config,
+        );
+        set_status(&env, session_id, &mut game, GameStatus::Active);
+
+        save_game(&env, session_id, &game);
+        index_player_game(&env, &game.player1, session_id);
+        index_player_game(&env, &game.player2, session_id);
+
+        // Topic: ["lobby_join", session_id]
+        // Data: [host, joiner]
+        publish_event(
+            &env,
+            "lobby_join",
+            session_id,
+            (game.player1.clone(), game.player2.clone()),
+        );
+        emit_game_start(&env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Cancel an open lobby so its session_id can be reused right away.
+    pub fn cancel_lobby(env: Env, session_id: u32, host: Address) -> Result<(), Error> {
+        host.require_auth();
+
+        let lobby = load_lobby(&env, session_id).ok_or(Error::LobbyNotFound)?;
+
+        if lobby.host != host {
+            return Err(Error::NotPlayer);
+        }
+
+        remove_lobby(&env, session_id);
+        unlist_open_lobby(&env, session_id);
+
+        // Topic: ["lobby_cancel", session_id]
+        // Data: [host]
+        publish_event(&env, "lobby_cancel", session_id, host);
+
+        Ok(())
+    }
+
+    /// Hand an unjoined lobby to a new host, who stakes `new_points` in place of the old host.
+    pub fn transfer_lobby(
+        env: Env,
+        session_id: u32,
+        host: Address,
+        new_host: Address,
+        new_points: i128,
+    ) -> Result<(), Error> {
+        host.require_auth();
+
+        check_points(new_points)?;
+
+        let mut lobby = load_lobby(&env, session_id).ok_or(Error::LobbyNotFound)?;
+
+        if lobby.host != host {
+            return Err(Error::NotPlayer);
+        }
+        // The invited opponent can't take over either, or nobody could join.
+        if new_host == host || lobby.expected_opponent.as_ref() == Some(&new_host) {
+            return Err(Error::SelfPlay);
+        }
+        if lobby_expired(&env, &lobby) {
+            return Err(Error::LobbyExpired);
+        }
+        check_pot(&env, new_points, 0)?;
+
+        new_host.require_auth_for_args(
+            vec![&env, session_id.into_val(&env), new_points.into_val(&env)],
+        );
+
+        lobby.host = new_host.clone();
+        lobby.host_points = new_points;
+        save_lobby(&env, session_id, &lobby);
+
+        // Topic: ["lobby_transfer", session_id]
+        // Data: [host, new_host, new_points]
+        publish_event(&env, "lobby_transfer", session_id, (host, new_host, new_points));
+
+        Ok(())
+    }
+
+    /// Delete a lobby nobody joined within `LOBBY_TTL_LEDGERS`. Callable by anyone.
+    pub fn reap_lobby(env: Env, session_id: u32) -> Result<(), Error> {
+        let lobby = load_lobby(&env, session_id).ok_or(Error::LobbyNotFound)?;
+
+        if !lobby_expired(&env, &lobby) {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        remove_lobby(&env, session_id);
+        unlist_open_lobby(&env, session_id);
+
+        // Topic: ["lobby_expired", session_id]
+        // Data: [host]
+        publish_event(&env, "lobby_expired", session_id, lobby.host);
+
+        Ok(())
+    }
+
+    /// Address of the player to move, so clients don't have to map `whose_turn` themselves.
+    pub fn current_player(env: Env, session_id: u32) -> Result<Address, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if game.winner.is_some()
+            || matches!(
+                game.status,
+                GameStatus::Completed | GameStatus::Timeout | GameStatus::Draw | GameStatus::Aborted
+            )
+        {
+            return Err(Error::GameAlreadyEnded);
+        }
+        Ok(if game.whose_turn == 1 {
+            game.player1
+        } else {
+            game.player2
+        })
+    }
+
+    /// Who pinged first in the current (or last finished) round: 1 = player1, 2 = player2.
+    ///
+    /// This is the opener resolved when the round started. For coin-flip games it can be
+    /// checked against the randomness output in the fairness proof.
+    pub fn get_first_mover(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(game.round_opener)
+    }
+
+    /// Game or lobby for a session in one call, checking the game first.
+    /// Returns `SessionState::None` instead of an error when the slot is empty.
+    pub fn get_session(env: Env, session_id: u32) -> SessionState {
+        if let Some(game) = load_game(&env, session_id) {
+            return SessionState::Game(game);
+        }
+        match load_lobby(&env, session_id) {
+            Some(lobby) => SessionState::Lobby(lobby),
+            None => SessionState::None,
+        }
+    }
+
+    /// Session ids of lobbies waiting for an opponent, oldest first.
+    pub fn get_open_lobbies(env: Env) -> Vec<u32> {
+        let mut ids = Vec::new(&env);
+        for (session_id, _) in live_open_lobbies(&env).iter() {
+            ids.push_back(session_id);
+        }
+        ids
+    }
+
+    /// Read-only lobby state query.
+    pub fn get_lobby(env: Env, session_id: u32) -> Result<Lobby, Error> {
+        load_lobby(&env, session_id).ok_or(Error::LobbyNotFound)
+    }
+
+    /// Find the player's most recent game that has not finished yet.
+    pub fn get_current_game(env: Env, player: Address) -> Result<(u32, Game), Error> {
+        let sessions: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::PlayerGames(player))
+            .unwrap_or(Vec::new(&env));
+        for session_id in sessions.iter().rev() {
+            if let Some(game) = load_game(&env, session_id) {
+                let in_play = matches!(game.status, GameStatus::Active | GameStatus::RoundOver);
+                if game.winner.is_none() && in_play {
+                    return Ok((session_id, game));
+                }
+            }
+        }
+        Err(Error::GameNotFound)
+    }
+
+    /// Ping counts for a game as `(total_pings, player1_pings, player2_pings)`.
+    ///
+    /// Unlike `current_turn`, the total includes a final winning ping.
+    pub fn get_turn_counts(env: Env, session_id: u32) -> Result<(u32, u32, u32), Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok((
+            game.player1_pings + game.player2_pings,
+            game.player1_pings,
+            game.player2_pings,
+        ))
+    }
+
+    /// Turns left before the game is decided by best distance (`max_turns - current_turn`).
+    pub fn get_remaining_turns(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(game.config.max_turns.saturating_sub(game.current_turn))
+    }
+
+    /// Ledgers until the waiting player may call `force_timeout`; `0` once the clock has run out
+    /// or when there is no such game. The deadline saturates at `u32::MAX`.
+    pub fn turns_until_timeout(env: Env, session_id: u32) -> u32 {
+        match load_game(&env, session_id) {
+            Some(game) => game
+                .last_action_ledger
+                .saturating_add(game.config.turn_timeout_ledgers)
+                .saturating_sub(env.ledger().sequence()),
+            None => 0,
+        }
+    }
+
+    /// Total points at risk for a session: both stakes for a game, or the host's
+    /// stake for an open lobby. Points are committed via auth, not held by this contract.
+    pub fn get_committed_stake(env: Env, session_id: u32) -> Result<i128, Error> {
+        if let Some(game) = load_game(&env, session_id) {
+            return Ok(game.player1_points + game.player2_points);
+        }
+        load_lobby(&env, session_id)
+            .map(|lobby| lobby.host_points)
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Read the randomness artifacts that bound the drop commitment at game start.
+    ///
+    /// Layout: `randomness_output (32) || drop_commitment (32) || randomness_signature (64)`.
+    /// Auditors can split these bytes and re-feed them, together with `session_id`,
+    /// to the randomness verifier's `verify_randomness`.
+    pub fn get_fairness_proof(env: Env, session_id: u32) -> Result<Bytes, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::FairnessProof(session_id))
+            .ok_or(Error::GameNotFound)
+    }
+
+    /// Lifetime results for a player; all zeros if they have never finished a game.
+    pub fn get_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stats(player))
+            .unwrap_or_default()
+    }
+
+    /// Full per-game ruleset, including contract-wide constants like the timeout.
+    pub fn get_rules(env: Env, session_id: u32) -> Result<RuleSet, Error> {
+        let game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        let config = game.config;
+        Ok(RuleSet {
+            grid_width: config.grid_width,
+            grid_height: config.grid_height,
+            metric: config.metric,
+            max_turns: config.max_turns,
+            timeout_ledgers: config.turn_timeout_ledgers,
+            min_pings_before_win: config.min_pings_before_win,
+            toroidal: config.toroidal,
+            retracts_allowed: config.retracts_allowed,
+            coin_flip_first_mover: config.coin_flip_first_mover,
+            rounds_to_win: config.rounds_to_win,
+            scoring_mode: config.scoring_mode,
+            player1_handicap: config.player1_handicap,
+            player2_handicap: config.player2_handicap,
+            player1_wins_ties: config.player1_wins_ties,
+            rules_hash: game.rules_hash,
+        })
+    }
+
+    /// Static feature set of this build: grid limits, metrics and proof systems.
+    pub fn get_capabilities(env: Env) -> Capabilities {
+        Capabilities {
+            max_grid_size: MAX_GRID_SIZE,
+            supported_metrics: Bytes::from_array(
+                &env,
+                &[
+                    METRIC_MANHATTAN as u8,
+                    METRIC_CHEBYSHEV as u8,
+                    METRIC_EUCLIDEAN_SQ as u8,
+                ],
+            ),
+            supports_risc0: false,
+            supports_ultrahonk: true,
+        }
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+    }
+
+    pub fn get_randomness_verifier(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::RandomnessVerifierId)
+            .expect("RandomnessVerifierId not set")
+    }
+
+    pub fn set_randomness_verifier(env: Env, new_verifier: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::RandomnessVerifierId, &new_verifier);
+    }
+
+    pub fn set_verifier(env: Env, new_verifier: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::VerifierId, &new_verifier);
+    }
+
+    /// Batch-fetch games for a monitoring page; `None` for ids with no game.
+    /// Admin-only so the contract's storage can't be scraped wholesale.
+    pub fn get_games(env: Env, session_ids: Vec<u32>) -> Vec<Option<Game>> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        let mut games = Vec::new(&env);
+        for session_id in session_ids.iter() {
+            games.push_back(load_game(&env, session_id));
+        }
+        games
+    }
 
-        // Verify randomness artifacts before starting the game.
-        let randomness_verifier_addr: Address = env
+    /// Unwind a stuck game: no winner is recorded and the hub refunds both stakes.
+    pub fn admin_abort(env: Env, session_id: u32) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::RandomnessVerifierId)
-            .expect("RandomnessVerifierId not set");
-        verify_randomness(
-            &env,
-            &randomness_verifier_addr,
-            session_id,
-            &randomness_output,
-            &drop_commitment,
-            &randomness_signature,
-        )?;
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-        // Consume the lobby
-        env.storage().temporary().remove(&lobby_key);
+        let mut game = load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if !matches!(game.status, GameStatus::Active | GameStatus::RoundOver) {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Now both players are known — call Game Hub
-        let hub_addr: Address = env
+        set_status(&env, session_id, &mut game, GameStatus::Aborted);
+        game.winner = None;
+        game.last_action_ledger = env.ledger().sequence();
+        save_game(&env, session_id, &game);
+
+        let game_hub_addr: Address = env
             .storage()
             .instance()
             .get(&DataKey::GameHubAddress)
             .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &hub_addr);
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &lobby.host,
-            &joiner,
-            &lobby.host_points,
-            &joiner_points,
-        );
-
-        // Create the game directly as active (no commit phase).
-        let game = Game {
-            player1: lobby.host,
-            player2: joiner,
-            player1_points: lobby.host_points,
-            player2_points: joiner_points,
-            drop_commitment,
-            status: GameStatus::Active,
-            current_turn: 0,
-            whose_turn: 1,
-            player1_best_distance: NO_DISTANCE,
-            player2_best_distance: NO_DISTANCE,
-            winner: None,
-            last_action_ledger: env.ledger().sequence(),
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        // A hub without `refund_game` has no winnerless settlement, so the abort is only
+        // recorded here rather than awarding the pot through `end_game`. A missing
+        // function cannot be told apart from a trap; both are reported as `refunded: false`.
+        // A contract error is the hub refusing, and leaves the game open for a retry.
+        let refunded = match game_hub.try_refund_game(&session_id) {
+            Ok(_) => true,
+            Err(Ok(err)) if err.is_type(ScErrorType::Contract) => {
+                return Err(Error::RefundRejected);
+            }
+            Err(_) => false,
         };
 
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        // Topic: ["abort", session_id]
+        // Data: [player1, player2, refunded]
+        publish_event(&env, "abort", session_id, (game.player1, game.player2, refunded));
 
         Ok(())
     }
 
-    /// Read-only lobby state query.
-    pub fn get_lobby(env: Env, session_id: u32) -> Result<Lobby, Error> {
-        env.storage()
-            .temporary()
-            .get(&DataKey::Lobby(session_id))
-            .ok_or(Error::LobbyNotFound)
-    }
-
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    pub fn get_admin(env: Env) -> Address {
+    pub fn is_paused(env: Env) -> bool {
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
+    /// Emergency switch: stop `open_game`, `join_game`, `start_game` and `rematch` while
+    /// letting games already in flight play out.
+    pub fn set_paused(env: Env, paused: bool) {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .expect("Admin not set");
         admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().set(&DataKey::Paused, &paused);
     }
 
-    pub fn get_hub(env: Env) -> Address {
+    pub fn get_strict_proofs(env: Env) -> bool {
         env.storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+            .get(&DataKey::StrictProofs)
+            .unwrap_or(false)
     }
 
-    pub fn set_hub(env: Env, new_hub: Address) {
+    /// Ranked mode: a rejected ping proof forfeits the game to the opponent. Off by default.
+    pub fn set_strict_proofs(env: Env, enabled: bool) {
         let admin: Address = env
             .storage()
             .instance()
@@ -681,29 +1906,43 @@ impl DeadDropContract {
         admin.require_auth();
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .set(&DataKey::StrictProofs, &enabled);
     }
 
-    pub fn get_randomness_verifier(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::RandomnessVerifierId)
-            .expect("RandomnessVerifierId not set")
+    pub fn get_max_pot(env: Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::MaxPot)
     }
 
-    pub fn set_randomness_verifier(env: Env, new_verifier: Address) {
+    /// Cap the combined stake of a game. `None` removes the cap.
+    pub fn set_max_pot(env: Env, max_pot: Option<i128>) {
         let admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .expect("Admin not set");
         admin.require_auth();
+        match max_pot {
+            Some(max_pot) => env.storage().instance().set(&DataKey::MaxPot, &max_pot),
+            None => env.storage().instance().remove(&DataKey::MaxPot),
+        }
+    }
+
+    /// Public input count `submit_ping` expects from the circuit. There is no setter: the
+    /// count is fixed by the circuit layout, which `build_public_inputs` follows by index.
+    pub fn get_num_public_inputs(_env: Env) -> u32 {
+        NUM_PUBLIC_INPUTS as u32
+    }
+
+    pub fn get_public_inputs_version(env: Env) -> u32 {
         env.storage()
             .instance()
-            .set(&DataKey::RandomnessVerifierId, &new_verifier);
+            .get(&DataKey::PublicInputsVersion)
+            .unwrap_or(PUBLIC_INPUTS_VERSION)
     }
 
-    pub fn set_verifier(env: Env, new_verifier: Address) {
+    /// Set the layout version bound as the first public input. Proofs built against
+    /// any other version fail with `InvalidPublicInputs`.
+    pub fn set_public_inputs_version(env: Env, version: u32) {
         let admin: Address = env
             .storage()
             .instance()
@@ -712,7 +1951,7 @@ impl DeadDropContract {
         admin.require_auth();
         env.storage()
             .instance()
-            .set(&DataKey::VerifierId, &new_verifier);
+            .set(&DataKey::PublicInputsVersion, &version);
     }
 
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
@@ -725,20 +1964,688 @@ impl DeadDropContract {
         env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 
+    /// Convert legacy `Game(session_id)` entries to `GameV2` after an upgrade, instead of
+    /// waiting for their next write. Returns how many sessions were migrated.
+    pub fn migrate_games(env: Env, session_ids: Vec<u32>) -> u32 {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut migrated = 0u32;
+        for session_id in session_ids.iter() {
+            let legacy: Option<LegacyGame> =
+                env.storage().temporary().get(&DataKey::Game(session_id));
+            if let Some(legacy) = legacy {
+                save_game(&env, session_id, &game_from_legacy(&env, legacy));
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
     // ========================================================================
     // Internal Helpers
     // ========================================================================
 
-    fn determine_winner_by_distance(game: &Game) -> Address {
-        // Lower best distance wins. Player1 wins ties.
-        if game.player1_best_distance <= game.player2_best_distance {
-            game.player1.clone()
+    fn determine_winner_by_distance(game: &Game) -> Option<Address> {
+        // Lower score wins. Equal scores are a draw unless the config hands them to player1.
+        let (player1_score, player2_score) = max_turns_scores(game);
+        if player1_score < player2_score {
+            Some(game.player1.clone())
+        } else if player2_score < player1_score {
+            Some(game.player2.clone())
+        } else if game.config.player1_wins_ties {
+            Some(game.player1.clone())
         } else {
-            game.player2.clone()
+            None
+        }
+    }
+}
+
+// ============================================================================
+// Game Configuration
+// ============================================================================
+
+/// Hash every per-game rule parameter into a single ruleset identifier.
+fn rules_hash(env: &Env, config: &GameConfig) -> BytesN<32> {
+    env.crypto().sha256(&config.clone().to_xdr(env)).into()
+}
+
+/// New games are refused while the admin has paused intake; running games are unaffected.
+fn check_not_paused(env: &Env) -> Result<(), Error> {
+    if env.storage().instance().get(&DataKey::Paused).unwrap_or(false) {
+        return Err(Error::Paused);
+    }
+    Ok(())
+}
+
+/// Stakes must be positive and small enough that the hub's arithmetic cannot overflow.
+fn check_points(points: i128) -> Result<(), Error> {
+    if points <= 0 {
+        return Err(Error::InvalidDistance);
+    }
+    if points > MAX_POINTS {
+        return Err(Error::PointsTooLarge);
+    }
+    Ok(())
+}
+
+/// Reject stakes whose combined pot exceeds the admin-configured cap, if any.
+fn check_pot(env: &Env, player1_points: i128, player2_points: i128) -> Result<(), Error> {
+    let max_pot: Option<i128> = env.storage().instance().get(&DataKey::MaxPot);
+    if let Some(max_pot) = max_pot {
+        if player1_points + player2_points > max_pot {
+            return Err(Error::PotTooLarge);
+        }
+    }
+    Ok(())
+}
+
+/// Largest distance a ping can legitimately report under the game's metric.
+fn max_distance(config: &GameConfig) -> u32 {
+    // Each axis wraps on a torus, so neither axis is ever more than half the board away.
+    let (x_max, y_max) = if config.toroidal {
+        (config.grid_width / 2, config.grid_height / 2)
+    } else {
+        (config.grid_width - 1, config.grid_height - 1)
+    };
+    match config.metric {
+        METRIC_CHEBYSHEV => x_max.max(y_max),
+        // At most 255^2 + 255^2 on the largest board, well inside u32.
+        METRIC_EUCLIDEAN_SQ => x_max * x_max + y_max * y_max,
+        _ => x_max + y_max,
+    }
+}
+
+fn validate_config(config: &GameConfig) -> Result<(), Error> {
+    for side in [config.grid_width, config.grid_height] {
+        if !(MIN_GRID_SIZE..=MAX_GRID_SIZE).contains(&side) {
+            return Err(Error::InvalidDistance);
+        }
+    }
+    if config.max_turns == 0 || config.max_turns % 2 != 0 || config.max_turns > MAX_TURNS_LIMIT {
+        return Err(Error::InvalidDistance);
+    }
+    if config.turn_timeout_ledgers < MIN_TURN_TIMEOUT_LEDGERS
+        || config.turn_timeout_ledgers > MAX_TURN_TIMEOUT_LEDGERS
+    {
+        return Err(Error::InvalidDistance);
+    }
+    if config.rounds_to_win == 0 || config.rounds_to_win > MAX_ROUNDS_TO_WIN {
+        return Err(Error::InvalidDistance);
+    }
+    if config.metric > METRIC_EUCLIDEAN_SQ {
+        return Err(Error::InvalidDistance);
+    }
+    if config.scoring_mode > SCORING_TOTAL {
+        return Err(Error::InvalidDistance);
+    }
+    let max = max_distance(config);
+    if config.player1_handicap > max || config.player2_handicap > max {
+        return Err(Error::InvalidDistance);
+    }
+    // Each player gets max_turns / 2 pings; a higher threshold could never be met.
+    if config.min_pings_before_win > config.max_turns / 2 {
+        return Err(Error::InvalidDistance);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Game End Reporting
+// ============================================================================
+
+/// A fresh `Created` game with empty scores; callers move it to `Active`.
+fn new_game(
+    env: &Env,
+    player1: Address,
+    player2: Address,
+    (player1_points, player2_points): (i128, i128),
+    drop_commitment: BytesN<32>,
+    opener: u32,
+    config: GameConfig,
+) -> Game {
+    Game {
+        player1,
+        player2,
+        player1_points,
+        player2_points,
+        drop_commitment,
+        status: GameStatus::Created,
+        current_turn: 0,
+        whose_turn: opener,
+        round_opener: opener,
+        player1_best_distance: NO_DISTANCE,
+        player2_best_distance: NO_DISTANCE,
+        player1_pings: 0,
+        player2_pings: 0,
+        player1_total_distance: 0,
+        player2_total_distance: 0,
+        last_ping: LastPing {
+            pinger: 0,
+            previous_best: NO_DISTANCE,
+            ledger: 0,
+        },
+        retracted_turn: None,
+        player1_rounds: 0,
+        player2_rounds: 0,
+        winner: None,
+        win_reason: None,
+        last_action_ledger: env.ledger().sequence(),
+        rules_hash: rules_hash(env, &config),
+        config,
+    }
+}
+
+/// First pinger for a new game: player1 unless the config asks for a coin flip,
+/// in which case the low bit of `sha256(randomness_output || session_id)` decides.
+fn first_mover(
+    env: &Env,
+    session_id: u32,
+    randomness_output: &BytesN<32>,
+    config: &GameConfig,
+) -> u32 {
+    if !config.coin_flip_first_mover {
+        return 1;
+    }
+    let mut seed = Bytes::from_array(env, &randomness_output.to_array());
+    seed.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    let digest = env.crypto().sha256(&seed).to_array();
+    (digest[31] & 1) as u32 + 1
+}
+
+/// Each player's score at max turns: best single ping, or the sum of all pings, plus
+/// the seat's handicap.
+fn max_turns_scores(game: &Game) -> (u32, u32) {
+    let (player1_score, player2_score) = if game.config.scoring_mode == SCORING_TOTAL {
+        (game.player1_total_distance, game.player2_total_distance)
+    } else {
+        (game.player1_best_distance, game.player2_best_distance)
+    };
+    (
+        player1_score.saturating_add(game.config.player1_handicap),
+        player2_score.saturating_add(game.config.player2_handicap),
+    )
+}
+
+/// Emit a `[name, session_id]` event. This stays on `Events::publish` rather
+/// than `#[contractevent]` so the topic and data layout the backend indexer
+/// decodes is unchanged.
+#[allow(deprecated)]
+fn publish_event<D: IntoVal<Env, Val>>(env: &Env, name: &str, session_id: u32, data: D) {
+    env.events().publish((Symbol::new(env, name), session_id), data);
+}
+
+/// Change a game's status, emitting a `status` event when it actually changes.
+fn set_status(env: &Env, session_id: u32, game: &mut Game, new_status: GameStatus) {
+    if game.status == new_status {
+        return;
+    }
+    let old_status = core::mem::replace(&mut game.status, new_status.clone());
+
+    // Topic: ["status", session_id]
+    // Data: [old_status, new_status, ledger]
+    publish_event(
+        env,
+        "status",
+        session_id,
+        (old_status as u32, new_status as u32, env.ledger().sequence()),
+    );
+}
+
+/// Whether a round won by `round_winner` (`None` for a drawn round) decides the match:
+/// the winner reaches `rounds_to_win`, or a single-round game is drawn.
+fn round_ends_match(game: &Game, round_winner: Option<&Address>) -> bool {
+    match round_winner {
+        Some(winner) => {
+            let rounds = if *winner == game.player1 {
+                game.player1_rounds
+            } else {
+                game.player2_rounds
+            };
+            rounds + 1 >= game.config.rounds_to_win
+        }
+        None => game.config.rounds_to_win == 1,
+    }
+}
+
+/// Score a finished round (`None` = drawn). Reports the match to the hub once a
+/// player reaches `rounds_to_win`; a drawn single-round game ends as a `Draw`, or as
+/// a player1 win if the hub cannot record draws. Otherwise resets the board and waits
+/// for `next_round`.
+fn end_round(
+    env: &Env,
+    session_id: u32,
+    game: &mut Game,
+    round_winner: Option<Address>,
+    reason: WinReason,
+) -> Option<Address> {
+    let match_over = round_ends_match(game, round_winner.as_ref());
+    match round_winner {
+        Some(round_winner) => {
+            if round_winner == game.player1 {
+                game.player1_rounds += 1;
+            } else {
+                game.player2_rounds += 1;
+            }
+
+            if match_over {
+                game.winner = Some(round_winner.clone());
+                game.win_reason = Some(reason as u32);
+                set_status(env, session_id, game, GameStatus::Completed);
+
+                save_game(env, session_id, game);
+
+                report_game_end(env, session_id, game, &round_winner);
+
+                return Some(round_winner);
+            }
+
+            // Topic: ["round_end", session_id]
+            // Data: [round_winner, player1_rounds, player2_rounds]
+            publish_event(
+                env,
+                "round_end",
+                session_id,
+                (round_winner, game.player1_rounds, game.player2_rounds),
+            );
+        }
+        None if match_over => {
+            // Hubs without `end_game_draw` keep the original tie rule, so the game is
+            // recorded as the player1 win the hub settles instead of a draw.
+            if !hub_accepts_draw(env, session_id) {
+                let player1 = game.player1.clone();
+                return end_round(env, session_id, game, Some(player1), reason);
+            }
+            game.winner = None;
+            set_status(env, session_id, game, GameStatus::Draw);
+
+            save_game(env, session_id, game);
+
+            report_game_draw(env, session_id, game);
+
+            return None;
+        }
+        None => {
+            // A drawn round in a longer match scores nothing and is replayed.
+            // Topic: ["round_draw", session_id]
+            // Data: [player1_rounds, player2_rounds]
+            publish_event(
+                env,
+                "round_draw",
+                session_id,
+                (game.player1_rounds, game.player2_rounds),
+            );
+        }
+    }
+
+    set_status(env, session_id, game, GameStatus::RoundOver);
+    game.current_turn = 0;
+    // The player who did not open this round commits the next drop.
+    game.whose_turn = if game.round_opener == 1 { 2 } else { 1 };
+    game.player1_best_distance = NO_DISTANCE;
+    game.player2_best_distance = NO_DISTANCE;
+    game.player1_pings = 0;
+    game.player2_pings = 0;
+    game.player1_total_distance = 0;
+    game.player2_total_distance = 0;
+    game.last_ping = LastPing {
+        pinger: 0,
+        previous_best: NO_DISTANCE,
+        ledger: 0,
+    };
+    game.retracted_turn = None;
+
+    save_game(env, session_id, game);
+
+    None
+}
+
+/// Report a draw to Game Hub. Returns `false` if the hub has no `end_game_draw`.
+fn hub_accepts_draw(env: &Env, session_id: u32) -> bool {
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+    let game_hub = GameHubClient::new(env, &game_hub_addr);
+    game_hub.try_end_game_draw(&session_id).is_ok()
+}
+
+/// Record a draw the hub has accepted: stats and a `draw` event with the tied score.
+fn report_game_draw(env: &Env, session_id: u32, game: &Game) {
+    update_stats(env, &game.player1, |_| {});
+    update_stats(env, &game.player2, |_| {});
+
+    // Topic: ["draw", session_id]
+    // Data: [tied_score, rules_hash]
+    let (tied_score, _) = max_turns_scores(game);
+    publish_event(env, "draw", session_id, (tied_score, game.rules_hash.clone()));
+}
+
+fn emit_game_start(env: &Env, session_id: u32, game: &Game) {
+    // Topic: ["game_start", session_id]
+    // Data: [player1, player2, drop_commitment]
+    publish_event(
+        env,
+        "game_start",
+        session_id,
+        (
+            game.player1.clone(),
+            game.player2.clone(),
+            game.drop_commitment.clone(),
+        ),
+    );
+}
+
+/// Report the result to Game Hub and emit a `game_end` event with the ruleset hash.
+fn report_game_end(env: &Env, session_id: u32, game: &Game, winner: &Address) {
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+    let game_hub = GameHubClient::new(env, &game_hub_addr);
+    let player1_won = *winner == game.player1;
+    // Unequal wagers: tell the hub how much each side put up so it can settle, if it
+    // supports `end_game_with_stakes`. Otherwise the plain `end_game` result stands.
+    let (winner_points, loser_points) = if player1_won {
+        (game.player1_points, game.player2_points)
+    } else {
+        (game.player2_points, game.player1_points)
+    };
+    let settled_with_stakes = game.player1_points != game.player2_points
+        && game_hub
+            .try_end_game_with_stakes(&session_id, &player1_won, &winner_points, &loser_points)
+            .is_ok();
+    if !settled_with_stakes {
+        game_hub.end_game(&session_id, &player1_won);
+    }
+
+    let loser = if player1_won {
+        &game.player2
+    } else {
+        &game.player1
+    };
+    update_stats(env, winner, |stats| stats.wins += 1);
+    update_stats(env, loser, |stats| {
+        stats.losses += 1;
+        if game.status == GameStatus::Timeout {
+            stats.timeouts += 1;
+        }
+    });
+
+    // Topic: ["game_end", session_id]
+    // Data: [winner, rules_hash]
+    publish_event(env, "game_end", session_id, (winner.clone(), game.rules_hash.clone()));
+}
+
+// ============================================================================
+// Session Storage
+// ============================================================================
+
+/// Read a game, preferring the current `GameV2` key over the legacy `Game` key.
+fn load_game(env: &Env, session_id: u32) -> Option<Game> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::GameV2(session_id))
+        .or_else(|| {
+            env.storage()
+                .temporary()
+                .get(&DataKey::Game(session_id))
+                .map(|legacy| game_from_legacy(env, legacy))
+        })
+}
+
+/// Lift a legacy game into the current layout. Legacy games always used the default
+/// rules with player1 opening, so everything the old layout lacked is derived from that.
+fn game_from_legacy(env: &Env, legacy: LegacyGame) -> Game {
+    let mut game = new_game(
+        env,
+        legacy.player1,
+        legacy.player2,
+        (legacy.player1_points, legacy.player2_points),
+        legacy.drop_commitment,
+        1,
+        GameConfig::default(),
+    );
+    game.status = legacy.status;
+    game.current_turn = legacy.current_turn;
+    game.whose_turn = legacy.whose_turn;
+    game.player1_best_distance = legacy.player1_best_distance;
+    game.player2_best_distance = legacy.player2_best_distance;
+    // Player1 pinged on every even turn, player2 on every odd one.
+    game.player1_pings = legacy.current_turn.div_ceil(2);
+    game.player2_pings = legacy.current_turn / 2;
+    game.win_reason = legacy.winner.as_ref().map(|winner| {
+        let winner_best = if *winner == game.player1 {
+            game.player1_best_distance
+        } else {
+            game.player2_best_distance
+        };
+        let reason = if game.status == GameStatus::Timeout {
+            WinReason::Timeout
+        } else if winner_best == 0 {
+            WinReason::FoundDrop
+        } else {
+            WinReason::ClosestAtMaxTurns
+        };
+        reason as u32
+    });
+    game.winner = legacy.winner;
+    game.last_action_ledger = legacy.last_action_ledger;
+    game
+}
+
+/// Read a lobby, preferring the current `LobbyV2` key over the legacy `Lobby` key.
+/// Legacy lobbies were always public and used the default rules.
+fn load_lobby(env: &Env, session_id: u32) -> Option<Lobby> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::LobbyV2(session_id))
+        .or_else(|| {
+            env.storage()
+                .temporary()
+                .get(&DataKey::Lobby(session_id))
+                .map(|legacy: LegacyLobby| Lobby {
+                    host: legacy.host,
+                    host_points: legacy.host_points,
+                    created_ledger: legacy.created_ledger,
+                    config: GameConfig::default(),
+                    expected_opponent: None,
+                })
+        })
+}
+
+/// Write a lobby under `LobbyV2`, dropping any legacy `Lobby` entry for the slot.
+fn save_lobby(env: &Env, session_id: u32, lobby: &Lobby) {
+    let key = DataKey::LobbyV2(session_id);
+    env.storage().temporary().set(&key, lobby);
+    env.storage().temporary().remove(&DataKey::Lobby(session_id));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+fn remove_lobby(env: &Env, session_id: u32) {
+    env.storage().temporary().remove(&DataKey::LobbyV2(session_id));
+    env.storage().temporary().remove(&DataKey::Lobby(session_id));
+}
+
+fn game_exists(env: &Env, session_id: u32) -> bool {
+    env.storage().temporary().has(&DataKey::GameV2(session_id))
+        || env.storage().temporary().has(&DataKey::Game(session_id))
+}
+
+/// Write the game entry and refresh the TTL of every key tied to the session.
+///
+/// Games are always written under `GameV2`; a legacy `Game` entry is removed
+/// here, so pre-upgrade games migrate lazily on their next interaction.
+fn save_game(env: &Env, session_id: u32, game: &Game) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::GameV2(session_id), game);
+    env.storage().temporary().remove(&DataKey::Game(session_id));
+    extend_session_ttl(env, session_id, game);
+    for player in [&game.player1, &game.player2] {
+        let key = DataKey::PlayerGames(player.clone());
+        if env.storage().temporary().has(&key) {
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+}
+
+/// Append a session to the player's index of recent games, dropping the oldest
+/// entry once `MAX_PLAYER_GAMES` is reached.
+fn index_player_game(env: &Env, player: &Address, session_id: u32) {
+    let key = DataKey::PlayerGames(player.clone());
+    let mut sessions: Vec<u32> = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    // A rematch reuses its session id; move it to the back instead of duplicating it.
+    if let Some(index) = sessions.first_index_of(session_id) {
+        sessions.remove(index);
+    }
+    if sessions.len() >= MAX_PLAYER_GAMES {
+        sessions.pop_front();
+    }
+    sessions.push_back(session_id);
+    env.storage().temporary().set(&key, &sessions);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Count a finished game for `player` and apply the outcome-specific update.
+fn update_stats(env: &Env, player: &Address, apply: impl FnOnce(&mut PlayerStats)) {
+    let key = DataKey::Stats(player.clone());
+    let mut stats: PlayerStats = env.storage().persistent().get(&key).unwrap_or_default();
+    stats.games += 1;
+    apply(&mut stats);
+    env.storage().persistent().set(&key, &stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, STATS_TTL_LEDGERS, STATS_TTL_LEDGERS);
+}
+
+/// Listed lobbies as `(session_id, expiry_ledger)`, minus any that are no longer joinable.
+///
+/// Lobbies expire `LOBBY_TTL_LEDGERS` after `open_game`, so expiry is tracked here
+/// instead of reading every lobby entry.
+fn live_open_lobbies(env: &Env) -> Vec<(u32, u32)> {
+    let open: Vec<(u32, u32)> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::OpenLobbies)
+        .unwrap_or(Vec::new(env));
+    let current_ledger = env.ledger().sequence();
+    let mut live = Vec::new(env);
+    for (session_id, expiry_ledger) in open.iter() {
+        if expiry_ledger >= current_ledger {
+            live.push_back((session_id, expiry_ledger));
+        }
+    }
+    live
+}
+
+/// Add a lobby to the open list, dropping expired entries first.
+fn list_open_lobby(env: &Env, session_id: u32) -> Result<(), Error> {
+    let mut open = live_open_lobbies(env);
+    if open.len() >= MAX_OPEN_LOBBIES {
+        return Err(Error::LobbyAlreadyExists);
+    }
+    open.push_back((session_id, env.ledger().sequence() + LOBBY_TTL_LEDGERS));
+    save_open_lobbies(env, &open);
+    Ok(())
+}
+
+/// The list lives in its own temporary entry so it doesn't bloat instance storage.
+/// Every write pushes its TTL to the newest lobby's expiry, so by the time the entry
+/// lapses every lobby on it has expired as well.
+fn save_open_lobbies(env: &Env, open: &Vec<(u32, u32)>) {
+    env.storage().temporary().set(&DataKey::OpenLobbies, open);
+    env.storage()
+        .temporary()
+        .extend_ttl(&DataKey::OpenLobbies, LOBBY_TTL_LEDGERS, LOBBY_TTL_LEDGERS);
+}
+
+fn lobby_expired(env: &Env, lobby: &Lobby) -> bool {
+    env.ledger().sequence() > lobby.created_ledger.saturating_add(LOBBY_TTL_LEDGERS)
+}
+
+fn unlist_open_lobby(env: &Env, session_id: u32) {
+    let mut open = live_open_lobbies(env);
+    if let Some(index) = open.iter().position(|(id, _)| id == session_id) {
+        open.remove(index as u32);
+    }
+    save_open_lobbies(env, &open);
+}
+
+/// Append a ping to the session's history, dropping the oldest entry past `cap`.
+fn append_ping_log(env: &Env, session_id: u32, record: PingRecord, cap: u32) {
+    let key = DataKey::PingLog(session_id);
+    let mut log: Vec<PingRecord> = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if log.len() >= cap {
+        log.pop_front();
+    }
+    log.push_back(record);
+    env.storage().temporary().set(&key, &log);
+}
+
+/// Extend all session-scoped temporary entries together so none of them
+/// expires while the game itself is still live.
+fn extend_session_ttl(env: &Env, session_id: u32, game: &Game) {
+    let keys = [
+        DataKey::GameV2(session_id),
+        DataKey::FairnessProof(session_id),
+        DataKey::PingLog(session_id),
+        DataKey::ResumeHint(session_id, game.player1.clone()),
+        DataKey::ResumeHint(session_id, game.player2.clone()),
+    ];
+    for key in keys.iter() {
+        if env.storage().temporary().has(key) {
+            env.storage()
+                .temporary()
+                .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
         }
     }
 }
 
+// ============================================================================
+// Fairness Proof Storage
+// ============================================================================
+
+/// Persist the verified randomness artifacts so the drop commitment can be audited later.
+fn store_fairness_proof(
+    env: &Env,
+    session_id: u32,
+    randomness_output: &BytesN<32>,
+    drop_commitment: &BytesN<32>,
+    randomness_signature: &BytesN<64>,
+) {
+    let mut artifact = Bytes::from_array(env, &randomness_output.to_array());
+    artifact.append(&Bytes::from_array(env, &drop_commitment.to_array()));
+    artifact.append(&Bytes::from_array(env, &randomness_signature.to_array()));
+    debug_assert_eq!(artifact.len(), FAIRNESS_PROOF_LEN);
+
+    let key = DataKey::FairnessProof(session_id);
+    env.storage().temporary().set(&key, &artifact);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
 // ============================================================================
 // Public Inputs Construction
 // ============================================================================
@@ -753,23 +2660,30 @@ fn u32_to_field_bytes(env: &Env, value: u32) -> BytesN<32> {
 
 /// Build the expected public inputs vector from on-chain state.
 /// Order must match the Noir circuit's public input declarations:
-/// [session_id, turn, ping_x, ping_y, drop_commitment, expected_distance]
+/// [version, session_id, turn, ping_x, ping_y, drop_commitment, expected_distance, toroidal, grid_width, grid_height, metric]
 fn build_public_inputs(
     env: &Env,
+    version: u32,
     session_id: u32,
     turn: u32,
     ping_x: u32,
     ping_y: u32,
     drop_commitment: &BytesN<32>,
     distance: u32,
+    config: &GameConfig,
 ) -> Vec<BytesN<32>> {
     let mut inputs = Vec::new(env);
+    inputs.push_back(u32_to_field_bytes(env, version));
     inputs.push_back(u32_to_field_bytes(env, session_id));
     inputs.push_back(u32_to_field_bytes(env, turn));
     inputs.push_back(u32_to_field_bytes(env, ping_x));
     inputs.push_back(u32_to_field_bytes(env, ping_y));
     inputs.push_back(drop_commitment.clone());
     inputs.push_back(u32_to_field_bytes(env, distance));
+    inputs.push_back(u32_to_field_bytes(env, config.toroidal as u32));
+    inputs.push_back(u32_to_field_bytes(env, config.grid_width));
+    inputs.push_back(u32_to_field_bytes(env, config.grid_height));
+    inputs.push_back(u32_to_field_bytes(env, config.metric));
     inputs
 }
 
@@ -792,9 +2706,12 @@ fn verify_proof(
         &Symbol::new(env, "verify_proof"),
         args,
     );
+    // Only a contract error is a definite rejection. A trap cannot be told apart from
+    // a missing contract or function, so it is reported as a failed call.
     match result {
         Ok(Ok(_)) => Ok(()),
-        Ok(Err(_)) | Err(_) => Err(Error::ProofVerificationFailed),
+        Ok(Err(_)) | Err(Ok(InvokeError::Contract(_))) => Err(Error::ProofVerificationFailed),
+        Err(_) => Err(Error::VerifierCallFailed),
     }
 }
 
@@ -821,10 +2738,11 @@ fn verify_randomness(
         &Symbol::new(env, "verify_randomness"),
         args,
     );
-
+    // Anything but an explicit `true` means the drop commitment is not attested,
+    // including a trap from the signature check.
     match result {
         Ok(Ok(true)) => Ok(()),
-        Ok(Ok(false)) | Ok(Err(_)) | Err(_) => Err(Error::RandomnessVerificationFailed),
+        _ => Err(Error::RandomnessVerificationFailed),
     }
 }
 
@@ -1,8 +1,15 @@
 #![cfg(test)]
 
-use crate::{DeadDropContract, DeadDropContractClient, Error, GameStatus};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use crate::{
+    Capabilities, DataKey, DeadDropContract, DeadDropContractClient, Error, GameConfig, GameStatus,
+    Outcome, WinReason, METRIC_CHEBYSHEV, METRIC_EUCLIDEAN_SQ, METRIC_MANHATTAN, SCORING_BEST,
+    SCORING_TOTAL,
+};
+use soroban_sdk::testutils::storage::Temporary as _;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, xdr, Address, Bytes, BytesN, Env, Vec,
+};
 
 // ============================================================================
 // Mock Contracts
@@ -25,6 +32,58 @@ impl MockGameHub {
     }
 
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+
+    pub fn end_game_draw(_env: Env, _session_id: u32) {}
+
+    pub fn end_game_with_stakes(
+        env: Env,
+        _session_id: u32,
+        player1_won: bool,
+        winner_points: i128,
+        loser_points: i128,
+    ) {
+        env.storage()
+            .instance()
+            .set(&0u32, &(player1_won, winner_points, loser_points));
+    }
+
+    pub fn last_stakes(env: Env) -> Option<(bool, i128, i128)> {
+        env.storage().instance().get(&0u32)
+    }
+
+    pub fn refund_game(env: Env, session_id: u32) {
+        env.storage().instance().set(&1u32, &session_id);
+    }
+
+    pub fn last_refund(env: Env) -> Option<u32> {
+        env.storage().instance().get(&1u32)
+    }
+}
+
+/// A hub with only the reference interface: no draw, stakes or refund extensions.
+#[contract]
+pub struct BasicGameHub;
+
+#[contractimpl]
+impl BasicGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        env.storage().instance().set(&session_id, &player1_won);
+    }
+
+    pub fn ended(env: Env, session_id: u32) -> Option<bool> {
+        env.storage().instance().get(&session_id)
+    }
 }
 
 #[contract]
@@ -35,13 +94,81 @@ impl MockVerifier {
     pub fn verify_proof(_env: Env, _proof: Bytes, _public_inputs: Vec<BytesN<32>>) {}
 }
 
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VerifierError {
+    ProofRejected = 1,
+}
+
 #[contract]
 pub struct RejectVerifier;
 
 #[contractimpl]
 impl RejectVerifier {
+    pub fn verify_proof(
+        _env: Env,
+        _proof: Bytes,
+        _public_inputs: Vec<BytesN<32>>,
+    ) -> Result<(), VerifierError> {
+        Err(VerifierError::ProofRejected)
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum HubError {
+    RefundRefused = 1,
+}
+
+/// A hub whose `refund_game` refuses every refund.
+#[contract]
+pub struct RefusingGameHub;
+
+#[contractimpl]
+impl RefusingGameHub {
+    pub fn start_game(
+        _env: Env,
+        _game_id: Address,
+        _session_id: u32,
+        _player1: Address,
+        _player2: Address,
+        _player1_points: i128,
+        _player2_points: i128,
+    ) {
+    }
+
+    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+
+    pub fn refund_game(_env: Env, _session_id: u32) -> Result<(), HubError> {
+        Err(HubError::RefundRefused)
+    }
+}
+
+#[contract]
+pub struct PanicVerifier;
+
+#[contractimpl]
+impl PanicVerifier {
     pub fn verify_proof(_env: Env, _proof: Bytes, _public_inputs: Vec<BytesN<32>>) {
-        panic!("proof rejected");
+        panic!("verifier misconfigured");
+    }
+}
+
+#[contract]
+pub struct PanicRandomnessVerifier;
+
+#[contractimpl]
+impl PanicRandomnessVerifier {
+    pub fn verify_randomness(
+        _env: Env,
+        _session_id: u32,
+        _randomness_output: BytesN<32>,
+        _drop_commitment: BytesN<32>,
+        _randomness_signature: BytesN<64>,
+    ) -> bool {
+        panic!("signature check trapped");
     }
 }
 
@@ -192,17 +319,79 @@ fn make_public_inputs(
     ping_y: u32,
     drop_commitment: &BytesN<32>,
     distance: u32,
+) -> Vec<BytesN<32>> {
+    make_public_inputs_for(
+        env,
+        session_id,
+        turn,
+        ping_x,
+        ping_y,
+        drop_commitment,
+        distance,
+        &GameConfig::default(),
+    )
+}
+
+fn make_public_inputs_for(
+    env: &Env,
+    session_id: u32,
+    turn: u32,
+    ping_x: u32,
+    ping_y: u32,
+    drop_commitment: &BytesN<32>,
+    distance: u32,
+    config: &GameConfig,
 ) -> Vec<BytesN<32>> {
     let mut inputs = Vec::new(env);
+    inputs.push_back(u32_to_field_bytes(env, 1));
     inputs.push_back(u32_to_field_bytes(env, session_id));
     inputs.push_back(u32_to_field_bytes(env, turn));
     inputs.push_back(u32_to_field_bytes(env, ping_x));
     inputs.push_back(u32_to_field_bytes(env, ping_y));
     inputs.push_back(drop_commitment.clone());
     inputs.push_back(u32_to_field_bytes(env, distance));
+    inputs.push_back(u32_to_field_bytes(env, config.toroidal as u32));
+    inputs.push_back(u32_to_field_bytes(env, config.grid_width));
+    inputs.push_back(u32_to_field_bytes(env, config.grid_height));
+    inputs.push_back(u32_to_field_bytes(env, config.metric));
     inputs
 }
 
+/// Count events emitted by `contract` in the last invocation whose first topic is `name`.
+fn count_events(env: &Env, contract: &Address, name: &str) -> usize {
+    env.events()
+        .all()
+        .filter_by_contract(contract)
+        .events()
+        .iter()
+        .filter(|event| {
+            let xdr::ContractEventBody::V0(body) = &event.body;
+            matches!(
+                body.topics.first(),
+                Some(xdr::ScVal::Symbol(symbol)) if symbol.0.as_slice() == name.as_bytes()
+            )
+        })
+        .count()
+}
+
+fn last_event_data(env: &Env, contract: &Address, name: &str) -> Option<xdr::ScVal> {
+    env.events()
+        .all()
+        .filter_by_contract(contract)
+        .events()
+        .iter()
+        .rev()
+        .find_map(|event| {
+            let xdr::ContractEventBody::V0(body) = &event.body;
+            match body.topics.first() {
+                Some(xdr::ScVal::Symbol(symbol)) if symbol.0.as_slice() == name.as_bytes() => {
+                    Some(body.data.clone())
+                }
+                _ => None,
+            }
+        })
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -225,6 +414,7 @@ fn test_start_game() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let game = client.get_game(&session_id);
@@ -256,6 +446,7 @@ fn test_start_game_randomness_verification_failed() {
         &bad_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
     assert_dead_drop_error(&result, Error::RandomnessVerificationFailed);
 }
@@ -278,6 +469,7 @@ fn test_self_play_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
     assert_dead_drop_error(&result, Error::SelfPlay);
 }
@@ -288,7 +480,7 @@ fn test_open_and_join_game() {
     let session_id = 100u32;
     let points = 100_0000000i128;
 
-    client.open_game(&session_id, &player1, &points);
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
 
     let lobby = client.get_lobby(&session_id);
     assert_eq!(lobby.host, player1);
@@ -323,7 +515,7 @@ fn test_join_game_randomness_rejected() {
     let session_id = 101u32;
     let points = 100_0000000i128;
 
-    client.open_game(&session_id, &player1, &points);
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
 
     let drop_commitment = make_drop_commitment(&env, &[8u8; 32]);
     let (_output, randomness_signature) =
@@ -359,6 +551,7 @@ fn test_submit_ping() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let distance = 25u32;
@@ -400,6 +593,7 @@ fn test_wrong_turn_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let public_inputs = make_public_inputs(&env, session_id, 0, 0u32, 0u32, &drop_commitment, 10);
@@ -436,8 +630,11 @@ fn test_distance_zero_wins() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
+    assert_eq!(client.get_outcome(&session_id), Outcome::InProgress);
+
     let public_inputs = make_public_inputs(&env, session_id, 0, 20u32, 30u32, &drop_commitment, 0);
     let proof = Bytes::from_slice(&env, &[1, 2, 3]);
 
@@ -456,7 +653,11 @@ fn test_distance_zero_wins() {
 
     let game = client.get_game(&session_id);
     assert_eq!(game.status, GameStatus::Completed);
-    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(
+        client.get_outcome(&session_id),
+        Outcome::Win(player1, WinReason::FoundDrop)
+    );
 }
 
 #[test]
@@ -477,6 +678,7 @@ fn test_30_turns_closest_wins() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let proof = Bytes::from_slice(&env, &[1, 2, 3]);
@@ -523,9 +725,13 @@ fn test_30_turns_closest_wins() {
 
     let game = client.get_game(&session_id);
     assert_eq!(game.status, GameStatus::Completed);
-    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.winner, Some(player1.clone()));
     assert_eq!(game.player1_best_distance, 5);
     assert_eq!(game.player2_best_distance, 10);
+    assert_eq!(
+        client.get_outcome(&session_id),
+        Outcome::Win(player1, WinReason::ClosestAtMaxTurns)
+    );
 }
 
 #[test]
@@ -545,9 +751,10 @@ fn test_force_timeout() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
-    let result = client.try_force_timeout(&session_id, &player1);
+    let result = client.try_force_timeout(&session_id, &player2);
     assert_dead_drop_error(&result, Error::TimeoutNotReached);
 
     env.ledger().set(soroban_sdk::testutils::LedgerInfo {
@@ -561,12 +768,16 @@ fn test_force_timeout() {
         max_entry_ttl: u32::MAX / 2,
     });
 
-    let winner = client.force_timeout(&session_id, &player1);
-    assert_eq!(winner, player1);
+    let winner = client.force_timeout(&session_id, &player2);
+    assert_eq!(winner, player2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.status, GameStatus::Timeout);
-    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(
+        client.get_outcome(&session_id),
+        Outcome::Win(player2, WinReason::Timeout)
+    );
 }
 
 #[test]
@@ -586,6 +797,7 @@ fn test_invalid_public_inputs_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let proof = Bytes::from_slice(&env, &[1, 2, 3]);
@@ -623,6 +835,7 @@ fn test_invalid_public_inputs_count_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let proof = Bytes::from_slice(&env, &[1, 2, 3]);
@@ -662,6 +875,7 @@ fn test_invalid_coordinates_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let public_inputs = make_public_inputs(&env, session_id, 0, 100u32, 0u32, &drop_commitment, 10);
@@ -697,6 +911,7 @@ fn test_invalid_distance_rejected() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let public_inputs = make_public_inputs(&env, session_id, 0, 0u32, 0u32, &drop_commitment, 101u32);
@@ -735,6 +950,7 @@ fn test_proof_failure_returns_contract_error() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
 
     let public_inputs = make_public_inputs(&env, session_id, 0, 0u32, 0u32, &drop_commitment, 10);
@@ -773,6 +989,7 @@ fn test_randomness_verifier_contract_error() {
         &randomness_output,
         &drop_commitment,
         &randomness_signature,
+        &GameConfig::default(),
     );
     assert_dead_drop_error(&result, Error::RandomnessVerificationFailed);
 }
@@ -797,6 +1014,7 @@ fn test_multiple_sessions_independent() {
         &out1,
         &drop1,
         &sig1,
+        &GameConfig::default(),
     );
     client.start_game(
         &2u32,
@@ -807,6 +1025,7 @@ fn test_multiple_sessions_independent() {
         &out2,
         &drop2,
         &sig2,
+        &GameConfig::default(),
     );
 
     let game1 = client.get_game(&1u32);
@@ -819,3 +1038,3880 @@ fn test_multiple_sessions_independent() {
     assert_eq!(game1.drop_commitment, drop1);
     assert_eq!(game2.drop_commitment, drop2);
 }
+
+#[test]
+fn test_fairness_proof_round_trips_through_randomness_verifier() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 150u32;
+    let drop_commitment = make_drop_commitment(&env, &[23u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let artifact = client.get_fairness_proof(&session_id);
+    assert_eq!(artifact.len(), 128);
+
+    let stored_output: BytesN<32> = artifact.slice(0..32).try_into().unwrap();
+    let stored_commitment: BytesN<32> = artifact.slice(32..64).try_into().unwrap();
+    let stored_signature: BytesN<64> = artifact.slice(64..128).try_into().unwrap();
+    assert_eq!(stored_output, randomness_output);
+    assert_eq!(stored_commitment, drop_commitment);
+    assert_eq!(stored_signature, randomness_signature);
+
+    let verifier = MockRandomnessVerifierClient::new(&env, &client.get_randomness_verifier());
+    assert!(verifier.verify_randomness(
+        &session_id,
+        &stored_output,
+        &stored_commitment,
+        &stored_signature,
+    ));
+}
+
+#[test]
+fn test_fairness_proof_missing_session() {
+    let (_env, client, _player1, _player2) = setup_test();
+    let result = client.try_get_fairness_proof(&999u32);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+}
+
+#[test]
+fn test_submit_ping_extends_all_session_keys() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 160u32;
+    let drop_commitment = make_drop_commitment(&env, &[24u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    // Use a short minimum TTL so the game TTL actually counts down.
+    env.ledger().set_min_temp_entry_ttl(16);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Jump close to expiry.
+    env.ledger().with_mut(|li| li.sequence_number += 518_000);
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 1u32, 2u32, &drop_commitment, 30);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &30u32,
+        &1u32,
+        &2u32,
+        &proof,
+        &public_inputs,
+    );
+
+    env.as_contract(&client.address, || {
+        let game_ttl = env.storage().temporary().get_ttl(&DataKey::GameV2(session_id));
+        let proof_ttl = env
+            .storage()
+            .temporary()
+            .get_ttl(&DataKey::FairnessProof(session_id));
+        let log_ttl = env.storage().temporary().get_ttl(&DataKey::PingLog(session_id));
+        assert_eq!(game_ttl, 518_400);
+        assert_eq!(proof_ttl, 518_400);
+        assert_eq!(log_ttl, 518_400);
+    });
+
+    assert_eq!(client.get_game(&session_id).current_turn, 1);
+    assert_eq!(client.get_fairness_proof(&session_id).len(), 128);
+}
+
+#[test]
+fn test_distance_input_mismatch_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 180u32;
+    let drop_commitment = make_drop_commitment(&env, &[27u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Proof attests distance 11, caller claims 10.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 11);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::DistanceInputMismatch);
+}
+
+#[test]
+fn test_bounded_game_rejects_wrapped_proof() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 190u32;
+    let drop_commitment = make_drop_commitment(&env, &[28u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    let bounded = GameConfig {
+        toroidal: false,
+        ..GameConfig::default()
+    };
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &bounded,
+    );
+    assert!(!client.get_game(&session_id).config.toroidal);
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // A proof generated under wrapping semantics binds toroidal = 1.
+    let wrapped_inputs = make_public_inputs(&env, session_id, 0, 99u32, 99u32, &drop_commitment, 4);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &4u32,
+        &99u32,
+        &99u32,
+        &proof,
+        &wrapped_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+
+    // Bounded distances can exceed the toroidal maximum.
+    let bounded_inputs =
+        make_public_inputs_for(&env, session_id, 0, 99u32, 99u32, &drop_commitment, 196, &bounded);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &196u32,
+        &99u32,
+        &99u32,
+        &proof,
+        &bounded_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).player1_best_distance, 196);
+}
+
+#[test]
+fn test_committed_stake_for_lobby_and_game() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 200u32;
+
+    let result = client.try_get_committed_stake(&session_id);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.open_game(&session_id, &player1, &30_0000000, &GameConfig::default(), &None);
+    assert_eq!(client.get_committed_stake(&session_id), 30_0000000);
+
+    let drop_commitment = make_drop_commitment(&env, &[29u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &player2,
+        &45_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_committed_stake(&session_id), 75_0000000);
+}
+
+#[test]
+fn test_unset_ping_sentinel_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 210u32;
+    let drop_commitment = make_drop_commitment(&env, &[30u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Clients that forget to set a ping often send (u32::MAX, u32::MAX).
+    let public_inputs =
+        make_public_inputs(&env, session_id, 0, u32::MAX, u32::MAX, &drop_commitment, 10);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &u32::MAX,
+        &u32::MAX,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+}
+
+#[test]
+fn test_turn_counts_after_alternating_pings() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 220u32;
+    let drop_commitment = make_drop_commitment(&env, &[31u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.get_turn_counts(&session_id), (0, 0, 0));
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    for turn in 0u32..3 {
+        let player = if turn % 2 == 0 { &player1 } else { &player2 };
+        let public_inputs = make_public_inputs(&env, session_id, turn, 3u32, 4u32, &drop_commitment, 20);
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &20u32,
+            &3u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    assert_eq!(client.get_turn_counts(&session_id), (3, 2, 1));
+
+    // A winning ping counts even though current_turn does not advance.
+    let public_inputs = make_public_inputs(&env, session_id, 3, 7u32, 8u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &3u32,
+        &0u32,
+        &7u32,
+        &8u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).current_turn, 3);
+    assert_eq!(client.get_turn_counts(&session_id), (4, 2, 2));
+}
+
+#[test]
+fn test_max_pot_enforced() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_max_pot(), None);
+    client.set_max_pot(&Some(150_0000000));
+    assert_eq!(client.get_max_pot(), Some(150_0000000));
+
+    let session_id = 230u32;
+    let drop_commitment = make_drop_commitment(&env, &[32u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_dead_drop_error(&result, Error::PotTooLarge);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &75_0000000,
+        &75_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.get_committed_stake(&session_id), 150_0000000);
+
+    // Lobby joins are capped too.
+    let lobby_session = 231u32;
+    client.open_game(&lobby_session, &player1, &100_0000000, &GameConfig::default(), &None);
+    let drop_commitment = make_drop_commitment(&env, &[33u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, lobby_session, &drop_commitment);
+    let result = client.try_join_game(
+        &lobby_session,
+        &player2,
+        &60_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::PotTooLarge);
+
+    client.set_max_pot(&None);
+    client.join_game(
+        &lobby_session,
+        &player2,
+        &60_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+}
+
+#[test]
+fn test_num_public_inputs_fixed() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_num_public_inputs(), 11);
+
+    let session_id = 240u32;
+    let drop_commitment = make_drop_commitment(&env, &[34u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 1u32, 1u32, &drop_commitment, 10);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let mut long_inputs = public_inputs.clone();
+    long_inputs.push_back(BytesN::from_array(&env, &[0u8; 32]));
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &1u32,
+        &1u32,
+        &proof,
+        &long_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &1u32,
+        &1u32,
+        &proof,
+        &public_inputs,
+    );
+}
+
+#[test]
+fn test_rules_hash_tracks_config() {
+    let (env, client, player1, player2) = setup_test();
+    let bounded = GameConfig {
+        toroidal: false,
+        ..GameConfig::default()
+    };
+    let configs = [GameConfig::default(), GameConfig::default(), bounded];
+
+    for (i, config) in configs.iter().enumerate() {
+        let session_id = 250u32 + i as u32;
+        let drop_commitment = make_drop_commitment(&env, &[35u8 + i as u8; 32]);
+        let (randomness_output, randomness_signature) =
+            make_randomness_artifacts(&env, session_id, &drop_commitment);
+        client.start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            config,
+        );
+    }
+
+    let hash_a = client.get_game(&250u32).rules_hash;
+    let hash_b = client.get_game(&251u32).rules_hash;
+    let hash_c = client.get_game(&252u32).rules_hash;
+    assert_eq!(hash_a, hash_b);
+    assert_ne!(hash_a, hash_c);
+}
+
+#[test]
+fn test_get_current_game_skips_completed() {
+    let (env, client, player1, player2) = setup_test();
+    let result = client.try_get_current_game(&player1);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    let active_session = 260u32;
+    let done_session = 261u32;
+    for (session_id, salt) in [(active_session, 40u8), (done_session, 41u8)] {
+        let drop_commitment = make_drop_commitment(&env, &[salt; 32]);
+        let (randomness_output, randomness_signature) =
+            make_randomness_artifacts(&env, session_id, &drop_commitment);
+        client.start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            &GameConfig::default(),
+        );
+    }
+
+    // Finish the most recent game with an immediate hit.
+    let done_commitment = client.get_game(&done_session).drop_commitment;
+    let public_inputs = make_public_inputs(&env, done_session, 0, 9u32, 9u32, &done_commitment, 0);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &done_session,
+        &player1,
+        &0u32,
+        &0u32,
+        &9u32,
+        &9u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let (session_id, game) = client.get_current_game(&player1);
+    assert_eq!(session_id, active_session);
+    assert_eq!(game.status, GameStatus::Active);
+    assert_eq!(client.get_current_game(&player2).0, active_session);
+}
+
+#[test]
+fn test_legacy_game_key_migrates_on_write() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 270u32;
+    let drop_commitment = make_drop_commitment(&env, &[42u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Simulate a game written before the upgrade, in the legacy layout and key.
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let game: crate::Game = storage.get(&DataKey::GameV2(session_id)).unwrap();
+        storage.remove(&DataKey::GameV2(session_id));
+        storage.set(&DataKey::Game(session_id), &legacy_game(&game));
+    });
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.round_opener, 1);
+    assert_eq!(game.config, GameConfig::default());
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 2u32, 3u32, &drop_commitment, 12);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &12u32,
+        &2u32,
+        &3u32,
+        &proof,
+        &public_inputs,
+    );
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        assert!(!storage.has(&DataKey::Game(session_id)));
+        assert!(storage.has(&DataKey::GameV2(session_id)));
+    });
+    assert_eq!(client.get_game(&session_id).current_turn, 1);
+}
+
+#[test]
+fn test_min_pings_before_win_delays_early_hit() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 280u32;
+    let drop_commitment = make_drop_commitment(&env, &[43u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            min_pings_before_win: 2,
+            ..GameConfig::default()
+        },
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // Player 1 hits the drop on their first ping, below the threshold.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 20u32, 20u32, &drop_commitment, 0);
+    let result = client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &20u32,
+        &20u32,
+        &proof,
+        &public_inputs,
+    );
+    assert!(result.is_none());
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Active);
+    assert_eq!(game.player1_best_distance, 0);
+
+    let public_inputs = make_public_inputs(&env, session_id, 1, 50u32, 50u32, &drop_commitment, 30);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &1u32,
+        &30u32,
+        &50u32,
+        &50u32,
+        &proof,
+        &public_inputs,
+    );
+
+    // Second ping meets the threshold; the recorded best of 0 wins.
+    let public_inputs = make_public_inputs(&env, session_id, 2, 21u32, 20u32, &drop_commitment, 1);
+    let result = client.submit_ping(
+        &session_id,
+        &player1,
+        &2u32,
+        &1u32,
+        &21u32,
+        &20u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(result, Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Completed);
+}
+
+#[test]
+fn test_min_pings_before_win_above_max_turns_rejected() {
+    let (_env, client, player1, _player2) = setup_test();
+    let result = client.try_open_game(
+        &281u32,
+        &player1,
+        &100_0000000,
+        &GameConfig {
+            min_pings_before_win: 16,
+            ..GameConfig::default()
+        },
+        &None,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+}
+
+#[test]
+fn test_retract_last_ping() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 290u32;
+    let drop_commitment = make_drop_commitment(&env, &[44u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            retracts_allowed: true,
+            ..GameConfig::default()
+        },
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 10u32, 10u32, &drop_commitment, 40);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &40u32,
+        &10u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+
+    // Only the last pinger may retract.
+    let result = client.try_retract_last_ping(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+
+    client.retract_last_ping(&session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.current_turn, 0);
+    assert_eq!(game.whose_turn, 1);
+    assert_eq!(game.player1_pings, 0);
+    assert_eq!(game.player1_best_distance, u32::MAX);
+    assert_eq!(game.player1_total_distance, 0);
+
+    // Re-ping, then once the opponent moves the retraction is gone.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 11u32, 10u32, &drop_commitment, 39);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &39u32,
+        &11u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+    let public_inputs = make_public_inputs(&env, session_id, 1, 60u32, 60u32, &drop_commitment, 20);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &1u32,
+        &20u32,
+        &60u32,
+        &60u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let result = client.try_retract_last_ping(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+    assert_eq!(client.get_game(&session_id).player1_best_distance, 39);
+}
+
+#[test]
+fn test_retract_disabled_by_default() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 291u32;
+    let drop_commitment = make_drop_commitment(&env, &[45u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 10u32, 10u32, &drop_commitment, 40);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &40u32,
+        &10u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let result = client.try_retract_last_ping(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::RetractNotAllowed);
+}
+
+#[test]
+fn test_blitz_config_bounds_and_turns() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 300u32;
+    let drop_commitment = make_drop_commitment(&env, &[46u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    let blitz = GameConfig {
+        grid_width: 50,
+        grid_height: 50,
+        max_turns: 4,
+        ..GameConfig::default()
+    };
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &blitz,
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // Off the 50x50 board.
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 60u32, 10u32, &drop_commitment, 10, &blitz);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &60u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+
+    // A proof bound to the default 100x100 board is rejected.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 10u32, 10u32, &drop_commitment, 10);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &10u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+
+    let mut result = None;
+    for turn in 0u32..4 {
+        let (player, distance) = if turn % 2 == 0 { (&player1, 8u32) } else { (&player2, 12u32) };
+        let public_inputs = make_public_inputs_for(
+            &env,
+            session_id,
+            turn,
+            10u32,
+            10u32,
+            &drop_commitment,
+            distance,
+            &blitz,
+        );
+        result = client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &10u32,
+            &10u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    assert_eq!(result, Some(player1));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Completed);
+}
+
+#[test]
+fn test_invalid_grid_and_turn_config_rejected() {
+    let (_env, client, player1, _player2) = setup_test();
+    let bad_configs = [
+        GameConfig {
+            grid_width: 5,
+            ..GameConfig::default()
+        },
+        GameConfig {
+            grid_height: 257,
+            ..GameConfig::default()
+        },
+        GameConfig {
+            max_turns: 31,
+            ..GameConfig::default()
+        },
+        GameConfig {
+            max_turns: 102,
+            ..GameConfig::default()
+        },
+        GameConfig {
+            metric: 3,
+            ..GameConfig::default()
+        },
+    ];
+    for config in bad_configs.iter() {
+        let result = client.try_open_game(&301u32, &player1, &100_0000000, config, &None);
+        assert_dead_drop_error(&result, Error::InvalidDistance);
+    }
+}
+
+#[test]
+fn test_get_capabilities() {
+    let (env, client, _player1, _player2) = setup_test();
+    let caps = client.get_capabilities();
+    assert_eq!(
+        caps,
+        Capabilities {
+            max_grid_size: 256,
+            supported_metrics: Bytes::from_array(
+                &env,
+                &[
+                    METRIC_MANHATTAN as u8,
+                    METRIC_CHEBYSHEV as u8,
+                    METRIC_EUCLIDEAN_SQ as u8
+                ]
+            ),
+            supports_risc0: false,
+            supports_ultrahonk: true,
+        }
+    );
+}
+
+#[test]
+fn test_concede_awards_opponent() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 310u32;
+    let drop_commitment = make_drop_commitment(&env, &[47u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let outsider = Address::generate(&env);
+    let result = client.try_concede(&session_id, &outsider);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+
+    let winner = client.concede(&session_id, &player1);
+    assert_eq!(winner, player2);
+    assert_eq!(count_events(&env, &client.address, "concede"), 1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(game.status, GameStatus::Completed);
+    assert_eq!(
+        client.get_outcome(&session_id),
+        Outcome::Win(player2.clone(), WinReason::Concede)
+    );
+
+    let result = client.try_concede(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_lobby_lifecycle_events() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 320u32;
+    let points = 100_0000000i128;
+
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    assert_eq!(count_events(&env, &client.address, "lobby_open"), 1);
+
+    let drop_commitment = make_drop_commitment(&env, &[48u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(count_events(&env, &client.address, "lobby_join"), 1);
+    assert_eq!(count_events(&env, &client.address, "game_start"), 1);
+
+    let session_id = 321u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &points,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(count_events(&env, &client.address, "game_start"), 1);
+}
+
+#[test]
+fn test_force_timeout_requires_active_game() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 330u32;
+    let drop_commitment = make_drop_commitment(&env, &[49u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let mut game: crate::Game = storage.get(&DataKey::GameV2(session_id)).unwrap();
+        game.status = GameStatus::Created;
+        storage.set(&DataKey::GameV2(session_id), &game);
+    });
+
+    env.ledger().with_mut(|li| li.sequence_number += 700);
+    let result = client.try_force_timeout(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::InvalidGameStatus);
+}
+
+#[test]
+fn test_cancel_lobby_frees_session() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 340u32;
+    let points = 100_0000000i128;
+
+    let result = client.try_cancel_lobby(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::LobbyNotFound);
+
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+
+    let result = client.try_cancel_lobby(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+
+    client.cancel_lobby(&session_id, &player1);
+    assert_eq!(count_events(&env, &client.address, "lobby_cancel"), 1);
+    let result = client.try_get_lobby(&session_id);
+    assert_dead_drop_error(&result, Error::LobbyNotFound);
+
+    // The room code can be reused immediately.
+    client.open_game(&session_id, &player2, &points, &GameConfig::default(), &None);
+    assert_eq!(client.get_lobby(&session_id).host, player2);
+}
+
+#[test]
+fn test_coin_flip_first_mover() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 350u32;
+    let config = GameConfig {
+        coin_flip_first_mover: true,
+        ..GameConfig::default()
+    };
+
+    // Deterministic and roughly balanced across randomness outputs.
+    let mut player2_first = 0u32;
+    for i in 0u32..200 {
+        let mut seed = [0u8; 32];
+        seed[..4].copy_from_slice(&i.to_be_bytes());
+        let output = BytesN::from_array(&env, &seed);
+        let mover = crate::first_mover(&env, session_id, &output, &config);
+        assert_eq!(mover, crate::first_mover(&env, session_id, &output, &config));
+        assert!(mover == 1 || mover == 2);
+        if mover == 2 {
+            player2_first += 1;
+        }
+    }
+    assert!((70..=130).contains(&player2_first));
+
+    let drop_commitment = make_drop_commitment(&env, &[50u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    let expected = crate::first_mover(&env, session_id, &randomness_output, &config);
+    assert_eq!(client.get_first_mover(&session_id), expected);
+    assert_eq!(client.get_game(&session_id).whose_turn, expected);
+
+    // Without the flag player1 always opens.
+    let session_id = 351u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.get_first_mover(&session_id), 1);
+}
+
+#[test]
+fn test_best_of_three_match() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 360u32;
+    let config = GameConfig {
+        rounds_to_win: 2,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[51u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 0);
+    let result = client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(result, None);
+    assert_eq!(count_events(&env, &client.address, "round_end"), 1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::RoundOver);
+    assert_eq!(game.player1_rounds, 1);
+    assert_eq!(game.current_turn, 0);
+    assert_eq!(game.player1_best_distance, u32::MAX);
+    assert!(game.winner.is_none());
+
+    // No pings until a new drop is committed, and the old drop cannot be reused.
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidGameStatus);
+    let result = client.try_next_round(
+        &session_id,
+        &player2,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::RandomnessVerificationFailed);
+
+    let drop_commitment = make_drop_commitment(&env, &[52u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    let result = client.try_next_round(
+        &session_id,
+        &player1,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+    client.next_round(
+        &session_id,
+        &player2,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 7u32, 7u32, &drop_commitment, 0);
+    let result = client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &7u32,
+        &7u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(result, Some(player1.clone()));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Completed);
+    assert_eq!(game.player1_rounds, 2);
+    assert_eq!(game.player2_rounds, 0);
+    assert_eq!(game.winner, Some(player1));
+}
+
+#[test]
+fn test_get_rules_matches_config() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 370u32;
+    let config = GameConfig {
+        grid_width: 64,
+        max_turns: 20,
+        toroidal: false,
+        min_pings_before_win: 2,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[53u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_get_rules(&session_id);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    let rules = client.get_rules(&session_id);
+    assert_eq!(rules.grid_width, 64);
+    assert_eq!(rules.grid_height, 100);
+    assert_eq!(rules.metric, METRIC_MANHATTAN);
+    assert_eq!(rules.max_turns, 20);
+    assert_eq!(rules.timeout_ledgers, 600);
+    assert_eq!(rules.min_pings_before_win, 2);
+    assert!(!rules.toroidal);
+    assert!(!rules.retracts_allowed);
+    assert!(!rules.coin_flip_first_mover);
+    assert_eq!(rules.rounds_to_win, 1);
+    assert_eq!(rules.rules_hash, client.get_game(&session_id).rules_hash);
+}
+
+#[test]
+fn test_tied_best_distance_is_draw() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 380u32;
+    let config = GameConfig {
+        max_turns: 2,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[54u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let mut result = None;
+    for (turn, player) in [(0u32, &player1), (1u32, &player2)] {
+        let public_inputs =
+            make_public_inputs_for(&env, session_id, turn, 4u32, 4u32, &drop_commitment, 9, &config);
+        result = client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &9u32,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    assert_eq!(result, None);
+    assert_eq!(count_events(&env, &client.address, "draw"), 1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Draw);
+    assert!(game.winner.is_none());
+    assert_eq!(client.get_outcome(&session_id), Outcome::Draw);
+
+    let result = client.try_concede(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_ping_log_records_history() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 390u32;
+    let drop_commitment = make_drop_commitment(&env, &[55u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_get_ping_log(&session_id);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            retracts_allowed: true,
+            ..GameConfig::default()
+        },
+    );
+    assert_eq!(client.get_ping_log(&session_id).len(), 0);
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 10u32, 20u32, &drop_commitment, 40);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &40u32,
+        &10u32,
+        &20u32,
+        &proof,
+        &public_inputs,
+    );
+    let public_inputs = make_public_inputs(&env, session_id, 1, 30u32, 40u32, &drop_commitment, 25);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &1u32,
+        &25u32,
+        &30u32,
+        &40u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let log = client.get_ping_log(&session_id);
+    assert_eq!(log.len(), 2);
+    let first = log.get(0).unwrap();
+    assert_eq!(first.turn, 0);
+    assert!(first.player_is_one);
+    assert_eq!((first.distance, first.x, first.y), (40, 10, 20));
+    let second = log.get(1).unwrap();
+    assert_eq!(second.turn, 1);
+    assert!(!second.player_is_one);
+    assert_eq!((second.distance, second.x, second.y), (25, 30, 40));
+
+    // A retracted ping is removed from the history.
+    client.retract_last_ping(&session_id, &player2);
+    assert_eq!(client.get_ping_log(&session_id).len(), 1);
+}
+
+#[test]
+fn test_turn_shot_clock() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 400u32;
+    let drop_commitment = make_drop_commitment(&env, &[56u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            turn_timeout_ledgers: 24,
+            ..GameConfig::default()
+        },
+    );
+    assert_eq!(client.get_game(&session_id).config.turn_timeout_ledgers, 24);
+
+    env.ledger().with_mut(|li| li.sequence_number += 23);
+    let result = client.try_force_timeout(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    assert_eq!(client.force_timeout(&session_id, &player2), player2);
+}
+
+#[test]
+fn test_force_timeout_only_waiting_player() {
+    let (env, client, player1, player2) = setup_test();
+    let drop_commitment = make_drop_commitment(&env, &[57u8; 32]);
+
+    // Player1 to move: player1 cannot claim, player2 can.
+    let session_id = 410u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Player2 to move after player1 pings: player2 cannot claim, player1 can.
+    let other_session = 411u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, other_session, &drop_commitment);
+    client.start_game(
+        &other_session,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs =
+        make_public_inputs(&env, other_session, 0, 10u32, 10u32, &drop_commitment, 30);
+    client.submit_ping(
+        &other_session,
+        &player1,
+        &0u32,
+        &30u32,
+        &10u32,
+        &10u32,
+        &proof,
+        &public_inputs,
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number += 700);
+
+    let result = client.try_force_timeout(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+    assert_eq!(client.force_timeout(&session_id, &player2), player2);
+
+    let result = client.try_force_timeout(&other_session, &player2);
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+    assert_eq!(client.force_timeout(&other_session, &player1), player1);
+}
+
+#[test]
+fn test_chebyshev_metric() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 420u32;
+    let chebyshev = GameConfig {
+        metric: METRIC_CHEBYSHEV,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[58u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &chebyshev,
+    );
+    assert_eq!(client.get_rules(&session_id).metric, METRIC_CHEBYSHEV);
+
+    // On a 100x100 torus no cell is more than 50 king moves away.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 3u32, 4u32, &drop_commitment, 51, &chebyshev);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &51u32,
+        &3u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+
+    // A proof bound to the Manhattan metric is rejected.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 3u32, 4u32, &drop_commitment, 50);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &50u32,
+        &3u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 3u32, 4u32, &drop_commitment, 50, &chebyshev);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &50u32,
+        &3u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).player1_best_distance, 50);
+}
+
+#[test]
+fn test_rematch_swaps_seats() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 430u32;
+    let drop_commitment = make_drop_commitment(&env, &[59u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &50_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let new_commitment = make_drop_commitment(&env, &[60u8; 32]);
+    let (new_output, new_signature) = make_randomness_artifacts(&env, session_id, &new_commitment);
+
+    // Not finished yet.
+    let result =
+        client.try_rematch(&session_id, &player1, &new_output, &new_commitment, &new_signature);
+    assert_dead_drop_error(&result, Error::InvalidGameStatus);
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let outsider = Address::generate(&env);
+    let result =
+        client.try_rematch(&session_id, &outsider, &new_output, &new_commitment, &new_signature);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+
+    let result = client.try_rematch(
+        &session_id,
+        &player1,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::RandomnessVerificationFailed);
+
+    client.rematch(&session_id, &player1, &new_output, &new_commitment, &new_signature);
+
+    // Player2 lost, so they take the player1 seat and move first.
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Active);
+    assert_eq!(game.player1, player2);
+    assert_eq!(game.player2, player1);
+    assert_eq!(game.player1_points, 50_0000000);
+    assert_eq!(game.player2_points, 100_0000000);
+    assert_eq!(game.whose_turn, 1);
+    assert_eq!(game.current_turn, 0);
+    assert_eq!(game.drop_commitment, new_commitment);
+    assert!(game.winner.is_none());
+    assert_eq!(client.get_ping_log(&session_id).len(), 0);
+
+    let (current_session, _) = client.get_current_game(&player1);
+    assert_eq!(current_session, session_id);
+}
+
+#[test]
+fn test_player_stats() {
+    let (env, client, player1, player2) = setup_test();
+    assert_eq!(client.get_stats(&player1), crate::PlayerStats::default());
+
+    let drop_commitment = make_drop_commitment(&env, &[61u8; 32]);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // Game 1: player1 finds the drop.
+    let session_id = 440u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+
+    // Game 2: player1 stalls and player2 claims the timeout.
+    let session_id = 441u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    env.ledger().with_mut(|li| li.sequence_number += 700);
+    client.force_timeout(&session_id, &player2);
+
+    // Game 3: player2 concedes.
+    let session_id = 442u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.concede(&session_id, &player2);
+
+    let stats = client.get_stats(&player1);
+    assert_eq!((stats.games, stats.wins, stats.losses, stats.timeouts), (3, 2, 1, 1));
+    let stats = client.get_stats(&player2);
+    assert_eq!((stats.games, stats.wins, stats.losses, stats.timeouts), (3, 1, 2, 0));
+}
+
+#[test]
+fn test_open_lobby_listing() {
+    let (env, client, player1, player2) = setup_test();
+    let points = 100_0000000i128;
+    assert_eq!(client.get_open_lobbies().len(), 0);
+
+    client.open_game(&450u32, &player1, &points, &GameConfig::default(), &None);
+    client.open_game(&451u32, &player2, &points, &GameConfig::default(), &None);
+    assert_eq!(client.get_open_lobbies(), Vec::from_array(&env, [450u32, 451u32]));
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&DataKey::OpenLobbies));
+        assert!(env.storage().temporary().has(&DataKey::OpenLobbies));
+    });
+
+    let drop_commitment = make_drop_commitment(&env, &[62u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, 450, &drop_commitment);
+    client.join_game(
+        &450u32,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_open_lobbies(), Vec::from_array(&env, [451u32]));
+
+    client.cancel_lobby(&451u32, &player2);
+    assert_eq!(client.get_open_lobbies().len(), 0);
+}
+
+#[test]
+fn test_open_lobby_listing_is_capped() {
+    let (_env, client, player1, _player2) = setup_test();
+    let points = 100_0000000i128;
+    for session_id in 1000u32..1256 {
+        client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    }
+    assert_eq!(client.get_open_lobbies().len(), 256);
+
+    let result = client.try_open_game(&1256u32, &player1, &points, &GameConfig::default(), &None);
+    assert_dead_drop_error(&result, Error::LobbyAlreadyExists);
+
+    client.cancel_lobby(&1000u32, &player1);
+    client.open_game(&1256u32, &player1, &points, &GameConfig::default(), &None);
+}
+
+#[test]
+fn test_unequal_stakes_reported_to_hub() {
+    let (env, client, player1, player2) = setup_test();
+    let hub = MockGameHubClient::new(&env, &client.get_hub());
+    let drop_commitment = make_drop_commitment(&env, &[63u8; 32]);
+
+    // Equal stakes keep using plain end_game.
+    let session_id = 460u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.concede(&session_id, &player1);
+    assert_eq!(hub.last_stakes(), None);
+
+    let session_id = 461u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &25_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.concede(&session_id, &player1);
+    assert_eq!(hub.last_stakes(), Some((false, 25_0000000, 100_0000000)));
+}
+
+#[test]
+fn test_get_session() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 470u32;
+    let points = 100_0000000i128;
+    assert_eq!(client.get_session(&session_id), crate::SessionState::None);
+
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    match client.get_session(&session_id) {
+        crate::SessionState::Lobby(lobby) => assert_eq!(lobby.host, player1),
+        other => panic!("expected lobby, got {:?}", other),
+    }
+
+    let drop_commitment = make_drop_commitment(&env, &[64u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(
+        client.get_session(&session_id),
+        crate::SessionState::Game(client.get_game(&session_id))
+    );
+}
+
+#[test]
+fn test_admin_get_games() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 480u32;
+    let drop_commitment = make_drop_commitment(&env, &[65u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let games = client.get_games(&Vec::from_array(&env, [session_id, 481u32]));
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, client.get_admin());
+    assert_eq!(games.len(), 2);
+    assert_eq!(games.get(0).unwrap(), Some(client.get_game(&session_id)));
+    assert_eq!(games.get(1).unwrap(), None);
+}
+
+#[test]
+fn test_rectangular_board() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 490u32;
+    let wide = GameConfig {
+        grid_width: 120,
+        grid_height: 80,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[66u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &wide,
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // x beyond the old 100 limit is on the board, y = 80 is not.
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 110u32, 80u32, &drop_commitment, 10, &wide);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &110u32,
+        &80u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+
+    // Max wrapped Manhattan distance is 120/2 + 80/2 = 100.
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 110u32, 79u32, &drop_commitment, 101, &wide);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &101u32,
+        &110u32,
+        &79u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 110u32, 79u32, &drop_commitment, 100, &wide);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &100u32,
+        &110u32,
+        &79u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).player1_best_distance, 100);
+}
+
+#[test]
+fn test_euclidean_squared_metric() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 491u32;
+    let radar = GameConfig {
+        metric: METRIC_EUCLIDEAN_SQ,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[67u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &radar,
+    );
+
+    // On a 100x100 torus the farthest cell is 50^2 + 50^2 = 5000 away.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 3u32, 4u32, &drop_commitment, 5001, &radar);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &5001u32,
+        &3u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+
+    let public_inputs =
+        make_public_inputs_for(&env, session_id, 0, 3u32, 4u32, &drop_commitment, 5000, &radar);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &5000u32,
+        &3u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).player1_best_distance, 5000);
+}
+
+#[test]
+fn test_admin_abort_refunds_game() {
+    let (env, client, player1, player2) = setup_test();
+    let hub = MockGameHubClient::new(&env, &client.get_hub());
+    let drop_commitment = make_drop_commitment(&env, &[68u8; 32]);
+
+    let session_id = 493u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    client.admin_abort(&session_id);
+    let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "abort") else {
+        panic!("missing abort event");
+    };
+    assert_eq!(data[2], xdr::ScVal::Bool(true));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Aborted);
+    assert_eq!(client.get_outcome(&session_id), Outcome::Aborted);
+    assert!(game.winner.is_none());
+    assert_eq!(hub.last_refund(), Some(session_id));
+
+    // An aborted game cannot be aborted again or conceded.
+    let result = client.try_admin_abort(&session_id);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_concede(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+
+    // Finished games keep their result.
+    let session_id = 494u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.concede(&session_id, &player1);
+    let result = client.try_admin_abort(&session_id);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+    assert_eq!(hub.last_refund(), Some(493u32));
+
+    let result = client.try_admin_abort(&495u32);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+}
+
+#[test]
+fn test_verifier_call_failure_is_distinct() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 496u32;
+    let drop_commitment = make_drop_commitment(&env, &[69u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 0u32, 0u32, &drop_commitment, 10);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // A verifier that panics never gave an answer, so it is not a proof rejection.
+    client.set_verifier(&env.register(PanicVerifier, ()));
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &10u32,
+        &0u32,
+        &0u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::VerifierCallFailed);
+
+    // Randomness failures keep their own error, whether the verifier traps or the
+    // function is missing.
+    let session_id = 497u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    for verifier in [
+        env.register(PanicRandomnessVerifier, ()),
+        env.register(RejectVerifier, ()),
+    ] {
+        client.set_randomness_verifier(&verifier);
+        let result = client.try_start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            &GameConfig::default(),
+        );
+        assert_dead_drop_error(&result, Error::RandomnessVerificationFailed);
+    }
+}
+
+#[test]
+fn test_remaining_turns_and_timeout_clock() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 498u32;
+    let drop_commitment = make_drop_commitment(&env, &[70u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.get_remaining_turns(&session_id), 30);
+    assert_eq!(client.turns_until_timeout(&session_id), 600);
+
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(client.turns_until_timeout(&session_id), 500);
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_remaining_turns(&session_id), 29);
+    assert_eq!(client.turns_until_timeout(&session_id), 600);
+
+    env.ledger().with_mut(|li| li.sequence_number += 700);
+    assert_eq!(client.turns_until_timeout(&session_id), 0);
+
+    let result = client.try_get_remaining_turns(&499u32);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+    assert_eq!(client.turns_until_timeout(&499u32), 0);
+}
+
+#[test]
+fn test_ping_event_carries_scoreboard() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 500u32;
+    let drop_commitment = make_drop_commitment(&env, &[71u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+
+    let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "ping") else {
+        panic!("missing ping event");
+    };
+    assert_eq!(data.len(), 7);
+    assert_eq!(data[2], xdr::ScVal::U32(20));
+    assert_eq!(data[5], xdr::ScVal::U32(20));
+    assert_eq!(data[6], xdr::ScVal::U32(2));
+}
+
+#[test]
+fn test_scoring_modes_at_max_turns() {
+    let (env, client, player1, player2) = setup_test();
+    let drop_commitment = make_drop_commitment(&env, &[72u8; 32]);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // Player1 lands the closest ping (5) but totals 45; player2 totals 22.
+    let pings = [(&player1, 5u32), (&player2, 10u32), (&player1, 40u32), (&player2, 12u32)];
+    let cases = [
+        (501u32, SCORING_BEST, player1.clone()),
+        (502u32, SCORING_TOTAL, player2.clone()),
+    ];
+
+    for (session_id, scoring_mode, expected_winner) in cases {
+        let config = GameConfig {
+            max_turns: 4,
+            scoring_mode,
+            ..GameConfig::default()
+        };
+        let (randomness_output, randomness_signature) =
+            make_randomness_artifacts(&env, session_id, &drop_commitment);
+        client.start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            &config,
+        );
+
+        let mut result = None;
+        for (turn, (player, distance)) in pings.iter().enumerate() {
+            let turn = turn as u32;
+            let public_inputs = make_public_inputs_for(
+                &env,
+                session_id,
+                turn,
+                4u32,
+                4u32,
+                &drop_commitment,
+                *distance,
+                &config,
+            );
+            result = client.submit_ping(
+                &session_id,
+                player,
+                &turn,
+                distance,
+                &4u32,
+                &4u32,
+                &proof,
+                &public_inputs,
+            );
+        }
+
+        assert_eq!(result, Some(expected_winner.clone()));
+        let game = client.get_game(&session_id);
+        assert_eq!(game.player1_total_distance, 45);
+        assert_eq!(game.player2_total_distance, 22);
+        assert_eq!(game.winner, Some(expected_winner));
+        assert_eq!(client.get_rules(&session_id).scoring_mode, scoring_mode);
+    }
+
+    let invalid = GameConfig {
+        scoring_mode: 2,
+        ..GameConfig::default()
+    };
+    let result = client.try_open_game(&503u32, &player1, &100_0000000, &invalid, &None);
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+}
+
+#[test]
+fn test_strict_proofs_forfeit_on_rejection() {
+    let (env, client, player1, player2) = setup_test();
+    assert!(!client.get_strict_proofs());
+    client.set_strict_proofs(&true);
+    assert!(client.get_strict_proofs());
+
+    let drop_commitment = make_drop_commitment(&env, &[73u8; 32]);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    for session_id in [504u32, 505u32] {
+        let (randomness_output, randomness_signature) =
+            make_randomness_artifacts(&env, session_id, &drop_commitment);
+        client.start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            &GameConfig::default(),
+        );
+    }
+
+    // A misconfigured verifier still just errors.
+    client.set_verifier(&env.register(PanicVerifier, ()));
+    let public_inputs = make_public_inputs(&env, 505, 0, 0u32, 0u32, &drop_commitment, 10);
+    let result = client.try_submit_ping(
+        &505u32,
+        &player1,
+        &0u32,
+        &10u32,
+        &0u32,
+        &0u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::VerifierCallFailed);
+    assert_eq!(client.get_game(&505u32).status, GameStatus::Active);
+
+    // A rejected proof hands the game to the opponent.
+    client.set_verifier(&env.register(RejectVerifier, ()));
+    let public_inputs = make_public_inputs(&env, 504, 0, 0u32, 0u32, &drop_commitment, 10);
+    let winner = client.submit_ping(
+        &504u32,
+        &player1,
+        &0u32,
+        &10u32,
+        &0u32,
+        &0u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(count_events(&env, &client.address, "forfeit"), 1);
+    assert_eq!(winner, Some(player2.clone()));
+    let game = client.get_game(&504u32);
+    assert_eq!(game.status, GameStatus::Completed);
+    assert_eq!(game.winner, Some(player2));
+}
+
+#[test]
+fn test_private_lobby_requires_invited_opponent() {
+    let (env, client, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+    let points = 100_0000000i128;
+    let drop_commitment = make_drop_commitment(&env, &[74u8; 32]);
+
+    // Private lobby: only player2 may join.
+    let session_id = 506u32;
+    client.open_game(
+        &session_id,
+        &player1,
+        &points,
+        &GameConfig::default(),
+        &Some(player2.clone()),
+    );
+    assert_eq!(client.get_lobby(&session_id).expected_opponent, Some(player2.clone()));
+
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    let result = client.try_join_game(
+        &session_id,
+        &outsider,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::NotInvited);
+
+    client.join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_game(&session_id).player2, player2);
+
+    // Open lobby: anyone with the room code may join.
+    let session_id = 507u32;
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &outsider,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_game(&session_id).player2, outsider);
+}
+
+#[test]
+fn test_current_player() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 508u32;
+    let drop_commitment = make_drop_commitment(&env, &[75u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_current_player(&session_id);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.current_player(&session_id), player1);
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.current_player(&session_id), player2);
+
+    client.concede(&session_id, &player2);
+    let result = client.try_current_player(&session_id);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_turn_parity_must_match_whose_turn() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 509u32;
+    let drop_commitment = make_drop_commitment(&env, &[76u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Simulate a desync: player2 to move while the turn is still even.
+    let mut game = client.get_game(&session_id);
+    game.whose_turn = 2;
+    env.as_contract(&client.address, || {
+        env.storage()
+            .temporary()
+            .set(&DataKey::GameV2(session_id), &game);
+    });
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player2,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidTurn);
+}
+
+#[test]
+fn test_timeout_clock_near_ledger_max() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 510u32;
+    let drop_commitment = make_drop_commitment(&env, &[77u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // last_action_ledger + turn_timeout_ledgers would overflow u32.
+    let mut game = client.get_game(&session_id);
+    game.last_action_ledger = u32::MAX - 100;
+    env.as_contract(&client.address, || {
+        env.storage()
+            .temporary()
+            .set(&DataKey::GameV2(session_id), &game);
+    });
+
+    let result = client.try_force_timeout(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::TimeoutNotReached);
+    assert_eq!(
+        client.turns_until_timeout(&session_id),
+        u32::MAX - env.ledger().sequence()
+    );
+}
+
+#[test]
+fn test_get_game_summary() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 511u32;
+    let drop_commitment = make_drop_commitment(&env, &[78u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_get_game_summary(&session_id);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    client.concede(&session_id, &player2);
+
+    let summary = client.get_game_summary(&session_id);
+    let game = client.get_game(&session_id);
+    assert_eq!(summary.status, GameStatus::Completed);
+    assert_eq!(summary.current_turn, game.current_turn);
+    assert_eq!(summary.whose_turn, 2);
+    assert_eq!(summary.winner, Some(player1));
+    assert_eq!(summary.player1_best_distance, 20);
+    assert_eq!(summary.player2_best_distance, u32::MAX);
+}
+
+#[test]
+fn test_expired_lobby_cannot_be_joined_and_can_be_reaped() {
+    let (env, client, player1, player2) = setup_test();
+    let points = 100_0000000i128;
+    let session_id = 512u32;
+    let drop_commitment = make_drop_commitment(&env, &[79u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    let result = client.try_reap_lobby(&session_id);
+    assert_dead_drop_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 7_201);
+    assert_eq!(client.get_open_lobbies().len(), 0);
+
+    let result = client.try_join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::LobbyExpired);
+
+    client.reap_lobby(&session_id);
+    assert_eq!(count_events(&env, &client.address, "lobby_expired"), 1);
+    let result = client.try_get_lobby(&session_id);
+    assert_dead_drop_error(&result, Error::LobbyNotFound);
+
+    // An expired slot can also be reopened directly without reaping.
+    let session_id = 513u32;
+    client.open_game(&session_id, &player1, &points, &GameConfig::default(), &None);
+    env.ledger().with_mut(|li| li.sequence_number += 7_201);
+    client.open_game(&session_id, &player2, &points, &GameConfig::default(), &None);
+    assert_eq!(client.get_lobby(&session_id).host, player2);
+    assert_eq!(client.get_open_lobbies(), Vec::from_array(&env, [session_id]));
+}
+
+#[test]
+fn test_handicap_applies_at_max_turns() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 514u32;
+    let config = GameConfig {
+        max_turns: 2,
+        player2_handicap: 10,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[80u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    // Player2 gets closer (5 vs 10) but 5 + 10 loses to 10.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let mut result = None;
+    for (turn, player, distance) in [(0u32, &player1, 10u32), (1u32, &player2, 5u32)] {
+        let public_inputs = make_public_inputs_for(
+            &env,
+            session_id,
+            turn,
+            4u32,
+            4u32,
+            &drop_commitment,
+            distance,
+            &config,
+        );
+        result = client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    assert_eq!(result, Some(player1.clone()));
+
+    // The handicap follows player2 into the player1 seat on a rematch.
+    let drop_commitment = make_drop_commitment(&env, &[81u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.rematch(
+        &session_id,
+        &player2,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1, player2);
+    assert_eq!(game.config.player1_handicap, 10);
+    assert_eq!(game.config.player2_handicap, 0);
+    assert_eq!(client.get_rules(&session_id).player1_handicap, 10);
+    assert_eq!(client.get_rules(&session_id).rules_hash, game.rules_hash);
+
+    let too_big = GameConfig {
+        player2_handicap: 101,
+        ..GameConfig::default()
+    };
+    let result = client.try_open_game(&515u32, &player1, &100_0000000, &too_big, &None);
+    assert_dead_drop_error(&result, Error::InvalidDistance);
+}
+
+#[test]
+fn test_status_transitions_emit_events() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 516u32;
+    let drop_commitment = make_drop_commitment(&env, &[82u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    let assert_status_event = |old: GameStatus, new: GameStatus| {
+        let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "status")
+        else {
+            panic!("missing status event");
+        };
+        assert_eq!(data.len(), 3);
+        assert_eq!(data[0], xdr::ScVal::U32(old as u32));
+        assert_eq!(data[1], xdr::ScVal::U32(new as u32));
+        assert_eq!(data[2], xdr::ScVal::U32(env.ledger().sequence()));
+    };
+    assert_status_event(GameStatus::Created, GameStatus::Active);
+
+    client.concede(&session_id, &player1);
+    assert_eq!(count_events(&env, &client.address, "status"), 1);
+    assert_status_event(GameStatus::Active, GameStatus::Completed);
+}
+
+#[test]
+fn test_transfer_lobby() {
+    let (env, client, player1, player2) = setup_test();
+    let organizer = Address::generate(&env);
+    let points = 100_0000000i128;
+    let session_id = 518u32;
+
+    client.open_game(&session_id, &organizer, &points, &GameConfig::default(), &None);
+
+    let result = client.try_transfer_lobby(&session_id, &player2, &player1, &points);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+    let result = client.try_transfer_lobby(&session_id, &organizer, &organizer, &points);
+    assert_dead_drop_error(&result, Error::SelfPlay);
+
+    client.transfer_lobby(&session_id, &organizer, &player1, &40_0000000);
+    assert_eq!(count_events(&env, &client.address, "lobby_transfer"), 1);
+    let lobby = client.get_lobby(&session_id);
+    assert_eq!(lobby.host, player1);
+    assert_eq!(lobby.host_points, 40_0000000);
+
+    let drop_commitment = make_drop_commitment(&env, &[84u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1, player1);
+    assert_eq!(game.player1_points, 40_0000000);
+}
+
+#[test]
+fn test_public_inputs_version_mismatch_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 519u32;
+    let drop_commitment = make_drop_commitment(&env, &[85u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_eq!(client.get_public_inputs_version(), 1);
+
+    // A proof against the old layout is rejected once the circuit is rolled forward.
+    client.set_public_inputs_version(&2);
+    let mut public_inputs =
+        make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+
+    public_inputs.set(0, u32_to_field_bytes(&env, 2));
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).current_turn, 1);
+}
+
+/// Per-call CPU ceiling for `submit_ping` with the mock verifier (~1.3M today). The real UltraHonk
+/// verifier needs most of the 100M transaction limit, so the game logic has to stay small.
+const SUBMIT_PING_CPU_BUDGET: u64 = 5_000_000;
+
+#[test]
+fn test_submit_ping_cost_stays_bounded() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 520u32;
+    let drop_commitment = make_drop_commitment(&env, &[86u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // A full 30-turn game: the last pings run against the longest ping log and end the game.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let mut max_cpu = 0u64;
+    for turn in 0u32..30 {
+        let player = if turn % 2 == 0 { &player1 } else { &player2 };
+        let distance = 10 + turn;
+        let public_inputs =
+            make_public_inputs(&env, session_id, turn, 4u32, 4u32, &drop_commitment, distance);
+
+        env.cost_estimate().budget().reset_unlimited();
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+        let cpu = env.cost_estimate().budget().cpu_instruction_cost();
+        max_cpu = max_cpu.max(cpu);
+    }
+    assert!(
+        max_cpu <= SUBMIT_PING_CPU_BUDGET,
+        "submit_ping used {} CPU instructions, budget is {}",
+        max_cpu,
+        SUBMIT_PING_CPU_BUDGET
+    );
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Completed);
+}
+
+#[test]
+fn test_pause_blocks_new_games_only() {
+    let (env, client, player1, player2) = setup_test();
+    let points = 100_0000000i128;
+    let drop_commitment = make_drop_commitment(&env, &[87u8; 32]);
+
+    let session_id = 521u32;
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &points,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.open_game(&522u32, &player1, &points, &GameConfig::default(), &None);
+
+    assert!(!client.is_paused());
+    client.set_paused(&true);
+    assert!(client.is_paused());
+
+    let result = client.try_open_game(&523u32, &player1, &points, &GameConfig::default(), &None);
+    assert_dead_drop_error(&result, Error::Paused);
+    let (join_output, join_signature) = make_randomness_artifacts(&env, 522, &drop_commitment);
+    let result = client.try_join_game(
+        &522u32,
+        &player2,
+        &points,
+        &join_output,
+        &drop_commitment,
+        &join_signature,
+    );
+    assert_dead_drop_error(&result, Error::Paused);
+    let (start_output, start_signature) = make_randomness_artifacts(&env, 524, &drop_commitment);
+    let result = client.try_start_game(
+        &524u32,
+        &player1,
+        &player2,
+        &points,
+        &points,
+        &start_output,
+        &drop_commitment,
+        &start_signature,
+        &GameConfig::default(),
+    );
+    assert_dead_drop_error(&result, Error::Paused);
+
+    // The running game continues.
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+
+    client.set_paused(&false);
+    client.join_game(
+        &522u32,
+        &player2,
+        &points,
+        &join_output,
+        &drop_commitment,
+        &join_signature,
+    );
+}
+
+#[test]
+fn test_bump_game_extends_ttl_without_state_change() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 525u32;
+    let drop_commitment = make_drop_commitment(&env, &[88u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    let result = client.try_bump_game(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    env.ledger().set_min_temp_entry_ttl(16);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    let before = client.get_game(&session_id);
+    client.set_resume_hint(&session_id, &player1, &Bytes::from_array(&env, &[1u8; 32]));
+
+    env.ledger().with_mut(|li| li.sequence_number += 518_000);
+    let outsider = Address::generate(&env);
+    let result = client.try_bump_game(&session_id, &outsider);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+    client.bump_game(&session_id, &player2);
+
+    env.as_contract(&client.address, || {
+        let game_ttl = env.storage().temporary().get_ttl(&DataKey::GameV2(session_id));
+        let proof_ttl = env
+            .storage()
+            .temporary()
+            .get_ttl(&DataKey::FairnessProof(session_id));
+        let hint_ttl = env
+            .storage()
+            .temporary()
+            .get_ttl(&DataKey::ResumeHint(session_id, player1.clone()));
+        assert_eq!(game_ttl, 518_400);
+        assert_eq!(proof_ttl, 518_400);
+        assert_eq!(hint_ttl, 518_400);
+    });
+    assert_eq!(client.get_game(&session_id), before);
+
+    client.concede(&session_id, &player1);
+    let result = client.try_bump_game(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_resume_hint_roundtrip() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 526u32;
+    let drop_commitment = make_drop_commitment(&env, &[89u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    let hint = Bytes::from_array(&env, &[7u8; 256]);
+
+    let result = client.try_set_resume_hint(&session_id, &player1, &hint);
+    assert_dead_drop_error(&result, Error::GameNotFound);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    assert_eq!(client.get_resume_hint(&session_id, &player1), None);
+    client.set_resume_hint(&session_id, &player1, &hint);
+    assert_eq!(client.get_resume_hint(&session_id, &player1), Some(hint.clone()));
+    assert_eq!(client.get_resume_hint(&session_id, &player2), None);
+
+    let too_large = Bytes::from_array(&env, &[7u8; 257]);
+    let result = client.try_set_resume_hint(&session_id, &player2, &too_large);
+    assert_dead_drop_error(&result, Error::ResumeHintTooLarge);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_set_resume_hint(&session_id, &outsider, &too_large);
+    assert_dead_drop_error(&result, Error::NotPlayer);
+
+    // A rematch starts without the previous game's hints.
+    client.set_resume_hint(&session_id, &player2, &hint);
+    client.concede(&session_id, &player1);
+    let drop_commitment = make_drop_commitment(&env, &[129u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.rematch(
+        &session_id,
+        &player1,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_eq!(client.get_resume_hint(&session_id, &player1), None);
+    assert_eq!(client.get_resume_hint(&session_id, &player2), None);
+}
+
+#[test]
+fn test_enormous_stake_rejected() {
+    let (env, client, player1, player2) = setup_test();
+    let points = 100_0000000i128;
+    let enormous = i128::MAX / 4 + 1;
+    let drop_commitment = make_drop_commitment(&env, &[90u8; 32]);
+
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, 527, &drop_commitment);
+    let result = client.try_start_game(
+        &527u32,
+        &player1,
+        &player2,
+        &points,
+        &enormous,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    assert_dead_drop_error(&result, Error::PointsTooLarge);
+
+    let result = client.try_open_game(&528u32, &player1, &enormous, &GameConfig::default(), &None);
+    assert_dead_drop_error(&result, Error::PointsTooLarge);
+
+    client.open_game(&528u32, &player1, &points, &GameConfig::default(), &None);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, 528, &drop_commitment);
+    let result = client.try_join_game(
+        &528u32,
+        &player2,
+        &enormous,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    assert_dead_drop_error(&result, Error::PointsTooLarge);
+
+    // The bound itself is still accepted.
+    client.join_game(
+        &528u32,
+        &player2,
+        &(i128::MAX / 4),
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+}
+
+#[test]
+fn test_new_best_event_only_on_improvement() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 529u32;
+    let drop_commitment = make_drop_commitment(&env, &[91u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    // Player1's distances: 30 (first ping is a record), 40 (worse), 12 (record).
+    let pings = [
+        (0u32, 30u32, 1usize),
+        (1, 50, 1),
+        (2, 40, 0),
+        (3, 50, 0),
+        (4, 12, 1),
+    ];
+    for (turn, distance, expected) in pings {
+        let player = if turn % 2 == 0 { &player1 } else { &player2 };
+        let public_inputs = make_public_inputs(&env, session_id, turn, 3u32, 4u32, &drop_commitment, distance);
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &3u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+        assert_eq!(count_events(&env, &client.address, "new_best"), expected);
+    }
+
+    let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "new_best")
+    else {
+        panic!("missing new_best event");
+    };
+    assert_eq!(data[1], xdr::ScVal::U32(12));
+}
+
+#[test]
+fn test_coin_flip_applies_to_next_round_and_rematch() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 530u32;
+    let config = GameConfig {
+        coin_flip_first_mover: true,
+        rounds_to_win: 2,
+        ..GameConfig::default()
+    };
+
+    // Pick a drop whose randomness makes player2 open, so the flip is observable.
+    let mut salt = 92u8;
+    let (drop_commitment, randomness_output, randomness_signature) = loop {
+        let drop_commitment = make_drop_commitment(&env, &[salt; 32]);
+        let (output, signature) = make_randomness_artifacts(&env, session_id, &drop_commitment);
+        if crate::first_mover(&env, session_id, &output, &config) == 2 {
+            break (drop_commitment, output, signature);
+        }
+        salt += 1;
+    };
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+    assert_eq!(client.get_first_mover(&session_id), 2);
+
+    // Player2 finds the drop and wins the first round.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).status, GameStatus::RoundOver);
+    assert_eq!(client.get_first_mover(&session_id), 2);
+
+    // The next round's opener comes from the new randomness.
+    let drop_commitment = make_drop_commitment(&env, &[salt + 1; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.next_round(
+        &session_id,
+        &player1,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    let opener = crate::first_mover(&env, session_id, &randomness_output, &config);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.whose_turn, opener);
+    assert_eq!(client.get_first_mover(&session_id), opener);
+
+    // Player2 takes the second round too, then the players rematch: the flip decides
+    // the opener again.
+    let mut turn = 0u32;
+    if opener == 1 {
+        let public_inputs = make_public_inputs(&env, session_id, 0, 6u32, 6u32, &drop_commitment, 10);
+        client.submit_ping(
+            &session_id,
+            &player1,
+            &0u32,
+            &10u32,
+            &6u32,
+            &6u32,
+            &proof,
+            &public_inputs,
+        );
+        turn = 1;
+    }
+    let public_inputs = make_public_inputs(&env, session_id, turn, 6u32, 6u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &turn,
+        &0u32,
+        &6u32,
+        &6u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Completed);
+    let drop_commitment = make_drop_commitment(&env, &[salt + 2; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.rematch(
+        &session_id,
+        &player1,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+    let opener = crate::first_mover(&env, session_id, &randomness_output, &config);
+    assert_eq!(client.get_game(&session_id).whose_turn, opener);
+    assert_eq!(client.get_first_mover(&session_id), opener);
+}
+
+#[test]
+fn test_force_timeout_between_rounds() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 531u32;
+    let config = GameConfig {
+        rounds_to_win: 2,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[120u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    // Player1 opens and wins the first round, so player2 owes next_round.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(client.get_game(&session_id).status, GameStatus::RoundOver);
+
+    let result = client.try_force_timeout(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::TimeoutNotReached);
+
+    env.ledger().with_mut(|li| li.sequence_number += 700);
+    let result = client.try_force_timeout(&session_id, &player2);
+    assert_dead_drop_error(&result, Error::NotYourTurn);
+
+    assert_eq!(client.force_timeout(&session_id, &player1), player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Timeout);
+    assert_eq!(game.winner, Some(player1));
+}
+
+fn legacy_game(game: &crate::Game) -> crate::LegacyGame {
+    crate::LegacyGame {
+        player1: game.player1.clone(),
+        player2: game.player2.clone(),
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        drop_commitment: game.drop_commitment.clone(),
+        status: game.status.clone(),
+        current_turn: game.current_turn,
+        whose_turn: game.whose_turn,
+        player1_best_distance: game.player1_best_distance,
+        player2_best_distance: game.player2_best_distance,
+        winner: game.winner.clone(),
+        last_action_ledger: game.last_action_ledger,
+    }
+}
+
+#[test]
+fn test_migrate_games_converts_legacy_entries() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 532u32;
+    let drop_commitment = make_drop_commitment(&env, &[121u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // A legacy game three turns in.
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let mut game: crate::Game = storage.get(&DataKey::GameV2(session_id)).unwrap();
+        game.current_turn = 3;
+        game.whose_turn = 2;
+        game.player1_best_distance = 9;
+        game.player2_best_distance = 14;
+        storage.remove(&DataKey::GameV2(session_id));
+        storage.set(&DataKey::Game(session_id), &legacy_game(&game));
+    });
+
+    let migrated = client.migrate_games(&Vec::from_array(&env, [session_id, 999u32]));
+    assert_eq!(migrated, 1);
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        assert!(!storage.has(&DataKey::Game(session_id)));
+        assert!(storage.has(&DataKey::GameV2(session_id)));
+    });
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Active);
+    assert_eq!(game.current_turn, 3);
+    assert_eq!(game.whose_turn, 2);
+    assert_eq!(game.player1_pings, 2);
+    assert_eq!(game.player2_pings, 1);
+    assert_eq!(game.player1_best_distance, 9);
+    assert_eq!(game.rules_hash, crate::rules_hash(&env, &GameConfig::default()));
+    assert_eq!(client.migrate_games(&Vec::from_array(&env, [session_id])), 0);
+}
+
+#[test]
+fn test_player1_wins_ties_setting() {
+    let (env, client, player1, player2) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let cases = [
+        (533u32, SCORING_BEST, false, None),
+        (534u32, SCORING_BEST, true, Some(player1.clone())),
+        (535u32, SCORING_TOTAL, false, None),
+        (536u32, SCORING_TOTAL, true, Some(player1.clone())),
+    ];
+    for (session_id, scoring_mode, player1_wins_ties, expected_winner) in cases {
+        let config = GameConfig {
+            max_turns: 2,
+            scoring_mode,
+            player1_wins_ties,
+            ..GameConfig::default()
+        };
+        let drop_commitment = make_drop_commitment(&env, &[session_id as u8; 32]);
+        let (randomness_output, randomness_signature) =
+            make_randomness_artifacts(&env, session_id, &drop_commitment);
+        client.start_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &randomness_output,
+            &drop_commitment,
+            &randomness_signature,
+            &config,
+        );
+
+        let mut result = None;
+        for (turn, player) in [(0u32, &player1), (1u32, &player2)] {
+            let public_inputs = make_public_inputs_for(
+                &env,
+                session_id,
+                turn,
+                4u32,
+                4u32,
+                &drop_commitment,
+                9,
+                &config,
+            );
+            result = client.submit_ping(
+                &session_id,
+                player,
+                &turn,
+                &9u32,
+                &4u32,
+                &4u32,
+                &proof,
+                &public_inputs,
+            );
+        }
+        assert_eq!(result, expected_winner);
+        let game = client.get_game(&session_id);
+        if player1_wins_ties {
+            assert_eq!(game.status, GameStatus::Completed);
+            assert_eq!(game.winner, Some(player1.clone()));
+        } else {
+            assert_eq!(game.status, GameStatus::Draw);
+            assert!(game.winner.is_none());
+        }
+        assert_eq!(client.get_rules(&session_id).player1_wins_ties, player1_wins_ties);
+    }
+}
+
+#[test]
+fn test_basic_hub_falls_back_to_end_game() {
+    let (env, client, player1, player2) = setup_test();
+    let hub_addr = env.register(BasicGameHub, ());
+    client.set_hub(&hub_addr);
+    let hub = BasicGameHubClient::new(&env, &hub_addr);
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+
+    // A tie settles as a player1 win on a hub without end_game_draw.
+    let session_id = 537u32;
+    let config = GameConfig {
+        max_turns: 2,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[122u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+    for (turn, player) in [(0u32, &player1), (1u32, &player2)] {
+        let public_inputs =
+            make_public_inputs_for(&env, session_id, turn, 4u32, 4u32, &drop_commitment, 9, &config);
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &9u32,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Completed);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(
+        client.get_outcome(&session_id),
+        Outcome::Win(player1.clone(), WinReason::ClosestAtMaxTurns)
+    );
+    assert_eq!(hub.ended(&session_id), Some(true));
+
+    // Unequal stakes settle through plain end_game.
+    let session_id = 538u32;
+    let drop_commitment = make_drop_commitment(&env, &[123u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &50_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.concede(&session_id, &player1);
+    assert_eq!(hub.ended(&session_id), Some(false));
+
+    // An abort is recorded locally without picking a winner on the hub.
+    let session_id = 539u32;
+    let drop_commitment = make_drop_commitment(&env, &[124u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+    client.admin_abort(&session_id);
+    let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "abort") else {
+        panic!("missing abort event");
+    };
+    assert_eq!(data[2], xdr::ScVal::Bool(false));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Aborted);
+    assert_eq!(hub.ended(&session_id), None);
+}
+
+#[test]
+fn test_finished_game_without_winner_is_draw() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 540u32;
+    let drop_commitment = make_drop_commitment(&env, &[125u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let mut game: crate::Game = storage.get(&DataKey::GameV2(session_id)).unwrap();
+        game.status = GameStatus::Completed;
+        game.winner = None;
+        storage.set(&DataKey::GameV2(session_id), &game);
+    });
+
+    assert_eq!(client.get_outcome(&session_id), Outcome::Draw);
+}
+
+#[test]
+fn test_mock_verifier_records_forwarded_inputs() {
+    let (env, client, player1, player2) = setup_test();
+    let verifier_addr = env.register(mock_verifier::MockVerifier, ());
+    client.set_verifier(&verifier_addr);
+    let verifier = mock_verifier::MockVerifierClient::new(&env, &verifier_addr);
+
+    let session_id = 541u32;
+    let drop_commitment = make_drop_commitment(&env, &[126u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    // Inputs rejected by the contract's own checks never reach the verifier.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let public_inputs = make_public_inputs(&env, session_id, 0, 5u32, 5u32, &drop_commitment, 20);
+    let mut short_inputs = public_inputs.clone();
+    short_inputs.pop_back();
+    let result = client.try_submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &short_inputs,
+    );
+    assert_dead_drop_error(&result, Error::InvalidPublicInputs);
+    assert_eq!(verifier.call_count(), 0);
+
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &20u32,
+        &5u32,
+        &5u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(verifier.call_count(), 1);
+    assert_eq!(verifier.last_input_count(), 11);
+    assert_eq!(
+        verifier.last_first_input(),
+        Some(u32_to_field_bytes(&env, client.get_public_inputs_version()))
+    );
+
+    let public_inputs = make_public_inputs(&env, session_id, 1, 6u32, 6u32, &drop_commitment, 18);
+    client.submit_ping(
+        &session_id,
+        &player2,
+        &1u32,
+        &18u32,
+        &6u32,
+        &6u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(verifier.call_count(), 2);
+    assert_eq!(verifier.last_input_count(), 11);
+}
+
+#[test]
+fn test_draw_event_reports_handicapped_score() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 542u32;
+    let config = GameConfig {
+        max_turns: 2,
+        player1_handicap: 3,
+        ..GameConfig::default()
+    };
+    let drop_commitment = make_drop_commitment(&env, &[127u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &config,
+    );
+
+    // 7 + 3 ties with 10, so the draw reports 10 rather than player1's best of 7.
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    for (turn, player, distance) in [(0u32, &player1, 7u32), (1u32, &player2, 10u32)] {
+        let public_inputs = make_public_inputs_for(
+            &env,
+            session_id,
+            turn,
+            4u32,
+            4u32,
+            &drop_commitment,
+            distance,
+            &config,
+        );
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "draw") else {
+        panic!("missing draw event");
+    };
+    assert_eq!(data[0], xdr::ScVal::U32(10));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Draw);
+}
+
+#[test]
+fn test_admin_abort_fails_when_hub_refuses_refund() {
+    let (env, client, player1, player2) = setup_test();
+    client.set_hub(&env.register(RefusingGameHub, ()));
+
+    let session_id = 543u32;
+    let drop_commitment = make_drop_commitment(&env, &[128u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig::default(),
+    );
+
+    let result = client.try_admin_abort(&session_id);
+    assert_dead_drop_error(&result, Error::RefundRejected);
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+}
+
+#[test]
+fn test_retract_once_per_turn() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 544u32;
+    let drop_commitment = make_drop_commitment(&env, &[130u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            retracts_allowed: true,
+            ..GameConfig::default()
+        },
+    );
+
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let ping = |player: &Address, turn: u32, x: u32, distance: u32| {
+        let public_inputs =
+            make_public_inputs(&env, session_id, turn, x, 10u32, &drop_commitment, distance);
+        client.submit_ping(
+            &session_id,
+            player,
+            &turn,
+            &distance,
+            &x,
+            &10u32,
+            &proof,
+            &public_inputs,
+        );
+    };
+
+    // Ping, retract, ping again: the second ping on turn 0 stands.
+    ping(&player1, 0, 10, 40);
+    client.retract_last_ping(&session_id, &player1);
+    ping(&player1, 0, 11, 39);
+    let result = client.try_retract_last_ping(&session_id, &player1);
+    assert_dead_drop_error(&result, Error::RetractNotAllowed);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.current_turn, 1);
+    assert_eq!(game.player1_best_distance, 39);
+
+    // The next turn gets its own retract.
+    ping(&player2, 1, 60, 20);
+    client.retract_last_ping(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).current_turn, 1);
+}
+
+#[test]
+fn test_legacy_lobby_key_is_read_and_joined() {
+    let (env, client, player1, player2) = setup_test();
+    let session_id = 545u32;
+    let points = 100_0000000i128;
+
+    let config = GameConfig {
+        rounds_to_win: 2,
+        ..GameConfig::default()
+    };
+    client.open_game(&session_id, &player1, &points, &config, &None);
+
+    // Simulate a lobby opened before the upgrade, in the legacy layout and key.
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        let lobby: crate::Lobby = storage.get(&DataKey::LobbyV2(session_id)).unwrap();
+        storage.remove(&DataKey::LobbyV2(session_id));
+        storage.set(
+            &DataKey::Lobby(session_id),
+            &crate::LegacyLobby {
+                host: lobby.host,
+                host_points: lobby.host_points,
+                created_ledger: lobby.created_ledger,
+            },
+        );
+    });
+
+    let lobby = client.get_lobby(&session_id);
+    assert_eq!(lobby.host, player1);
+    assert_eq!(lobby.host_points, points);
+    assert_eq!(lobby.config, GameConfig::default());
+    assert_eq!(lobby.expected_opponent, None);
+
+    let drop_commitment = make_drop_commitment(&env, &[131u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.join_game(
+        &session_id,
+        &player2,
+        &points,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+    );
+
+    assert_eq!(client.get_game(&session_id).config, GameConfig::default());
+    env.as_contract(&client.address, || {
+        let storage = env.storage().temporary();
+        assert!(!storage.has(&DataKey::Lobby(session_id)));
+        assert!(!storage.has(&DataKey::LobbyV2(session_id)));
+    });
+}
+
+#[test]
+fn test_ping_event_whose_turn_after_resolution() {
+    let (env, client, player1, player2) = setup_test();
+    let proof = Bytes::from_slice(&env, &[1, 2, 3]);
+    let ping_whose_turn = || {
+        let Some(xdr::ScVal::Vec(Some(data))) = last_event_data(&env, &client.address, "ping")
+        else {
+            panic!("missing ping event");
+        };
+        data[6].clone()
+    };
+
+    // Finding the drop in a best-of-3 ends the round: the non-opener commits next.
+    let session_id = 546u32;
+    let drop_commitment = make_drop_commitment(&env, &[132u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            rounds_to_win: 2,
+            ..GameConfig::default()
+        },
+    );
+    let public_inputs = make_public_inputs(&env, session_id, 0, 4u32, 4u32, &drop_commitment, 0);
+    client.submit_ping(
+        &session_id,
+        &player1,
+        &0u32,
+        &0u32,
+        &4u32,
+        &4u32,
+        &proof,
+        &public_inputs,
+    );
+    assert_eq!(ping_whose_turn(), xdr::ScVal::U32(2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::RoundOver);
+    assert_eq!(game.whose_turn, 2);
+
+    // A drawn single-round game at max turns ends the match: no one moves next.
+    let session_id = 547u32;
+    let drop_commitment = make_drop_commitment(&env, &[133u8; 32]);
+    let (randomness_output, randomness_signature) =
+        make_randomness_artifacts(&env, session_id, &drop_commitment);
+    client.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &randomness_output,
+        &drop_commitment,
+        &randomness_signature,
+        &GameConfig {
+            max_turns: 2,
+            ..GameConfig::default()
+        },
+    );
+    for (turn, pinger) in [(0u32, &player1), (1u32, &player2)] {
+        let public_inputs =
+            make_public_inputs(&env, session_id, turn, 4u32, 4u32, &drop_commitment, 10);
+        client.submit_ping(
+            &session_id,
+            pinger,
+            &turn,
+            &10u32,
+            &4u32,
+            &4u32,
+            &proof,
+            &public_inputs,
+        );
+    }
+    assert_eq!(ping_whose_turn(), xdr::ScVal::U32(0));
+    assert_ne!(client.get_game(&session_id).status, GameStatus::Active);
+}
@@ -138,8 +138,8 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(!game.player1_rolled);
+    assert!(!game.player2_rolled);
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
@@ -5,8 +5,9 @@ use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
+/// (start_game, end_game, end_game_draw, end_game_with_stakes, refund_game) but does nothing
+/// internally. It exists purely for game contracts to compile and integrate
+/// during development.
 #[contract]
 pub struct MockGameHub;
 
@@ -26,6 +27,24 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+}
+
+#[contractevent]
+pub struct GameEndedWithStakes {
+    pub session_id: u32,
+    pub player1_won: bool,
+    pub winner_points: i128,
+    pub loser_points: i128,
+}
+
+#[contractevent]
+pub struct GameRefunded {
+    pub session_id: u32,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +96,48 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// End a game session with no winner
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn end_game_draw(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameDrawn { session_id }.publish(&env);
+    }
+
+    /// End a game session with unequal stakes
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `player1_won` - True if player1 won, false if player2 won
+    /// * `winner_points` - Points the winner staked
+    /// * `loser_points` - Points the loser staked
+    pub fn end_game_with_stakes(
+        env: Env,
+        session_id: u32,
+        player1_won: bool,
+        winner_points: i128,
+        loser_points: i128,
+    ) {
+        // No auth required for mock
+        GameEndedWithStakes {
+            session_id,
+            player1_won,
+            winner_points,
+            loser_points,
+        }
+        .publish(&env);
+    }
+
+    /// Cancel a game session and return both players' points
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being refunded
+    pub fn refund_game(env: Env, session_id: u32) {
+        // No auth required for mock
+        GameRefunded { session_id }.publish(&env);
+    }
 }
 
 #[cfg(test)]
@@ -86,12 +147,15 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
         let player2 = Address::generate(&env);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
+        client.end_game_draw(&2);
+        client.end_game_with_stakes(&3, &false, &500, &1000);
+        client.refund_game(&4);
     }
 }
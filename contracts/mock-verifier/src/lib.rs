@@ -0,0 +1,140 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Bytes, BytesN, Env, Vec,
+};
+
+/// Mock verifier contract for Dead Drop development
+///
+/// Stands in for both the UltraHonk proof verifier (`verify_proof`) and the
+/// randomness verifier (`verify_randomness`). It accepts everything by default;
+/// integration suites can flip it to reject mid-scenario with `set_mode`.
+/// Each `verify_proof` call is recorded so tests can assert what was forwarded.
+#[contract]
+pub struct MockVerifier;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Mode,
+    CallCount,
+    LastInputCount,
+    LastFirstInput,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MockVerifierError {
+    ProofRejected = 1,
+}
+
+#[contractimpl]
+impl MockVerifier {
+    /// Switch between accepting and rejecting every verification
+    ///
+    /// # Arguments
+    /// * `accept` - True to accept (the default), false to reject
+    pub fn set_mode(env: Env, accept: bool) {
+        // No auth required for mock
+        env.storage().instance().set(&DataKey::Mode, &accept);
+    }
+
+    /// Current mode; true if the mock accepts verifications
+    pub fn get_mode(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::Mode).unwrap_or(true)
+    }
+
+    /// Verify a proof; fails with `MockVerifierError::ProofRejected` in reject mode, like a real
+    /// verifier rejecting an invalid proof
+    pub fn verify_proof(env: Env, _proof: Bytes, public_inputs: Vec<BytesN<32>>) {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::CallCount, &(Self::call_count(env.clone()) + 1));
+        storage.set(&DataKey::LastInputCount, &public_inputs.len());
+        if let Some(first) = public_inputs.first() {
+            storage.set(&DataKey::LastFirstInput, &first);
+        }
+
+        if !Self::get_mode(env.clone()) {
+            panic_with_error!(&env, MockVerifierError::ProofRejected);
+        }
+    }
+
+    /// Number of `verify_proof` calls so far
+    pub fn call_count(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::CallCount).unwrap_or(0)
+    }
+
+    /// Number of public inputs passed to the last `verify_proof` call
+    pub fn last_input_count(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::LastInputCount).unwrap_or(0)
+    }
+
+    /// First public input of the last `verify_proof` call, if any
+    pub fn last_first_input(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::LastFirstInput)
+    }
+
+    /// Verify randomness artifacts; returns false in reject mode
+    pub fn verify_randomness(
+        env: Env,
+        _session_id: u32,
+        _randomness_output: BytesN<32>,
+        _drop_commitment: BytesN<32>,
+        _randomness_signature: BytesN<64>,
+    ) -> bool {
+        Self::get_mode(env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mode_toggle() {
+        let env = Env::default();
+        let contract_id = env.register(MockVerifier, ());
+        let client = MockVerifierClient::new(&env, &contract_id);
+        let proof = Bytes::from_array(&env, &[1, 2, 3]);
+        let inputs = Vec::from_array(&env, [BytesN::from_array(&env, &[0u8; 32])]);
+        let output = BytesN::from_array(&env, &[1u8; 32]);
+        let commitment = BytesN::from_array(&env, &[2u8; 32]);
+        let signature = BytesN::from_array(&env, &[3u8; 64]);
+
+        assert!(client.get_mode());
+        client.verify_proof(&proof, &inputs);
+        assert!(client.verify_randomness(&1, &output, &commitment, &signature));
+
+        client.set_mode(&false);
+        assert_eq!(
+            client.try_verify_proof(&proof, &inputs),
+            Err(Ok(MockVerifierError::ProofRejected.into()))
+        );
+        assert!(!client.verify_randomness(&1, &output, &commitment, &signature));
+
+        client.set_mode(&true);
+        client.verify_proof(&proof, &inputs);
+    }
+
+    #[test]
+    fn test_records_last_call() {
+        let env = Env::default();
+        let contract_id = env.register(MockVerifier, ());
+        let client = MockVerifierClient::new(&env, &contract_id);
+        let proof = Bytes::from_array(&env, &[1, 2, 3]);
+
+        assert_eq!(client.call_count(), 0);
+        assert_eq!(client.last_input_count(), 0);
+        assert_eq!(client.last_first_input(), None);
+
+        let first = BytesN::from_array(&env, &[7u8; 32]);
+        let inputs = Vec::from_array(&env, [first.clone(), BytesN::from_array(&env, &[8u8; 32])]);
+        client.verify_proof(&proof, &inputs);
+        client.verify_proof(&proof, &inputs);
+
+        assert_eq!(client.call_count(), 2);
+        assert_eq!(client.last_input_count(), 2);
+        assert_eq!(client.last_first_input(), Some(first));
+    }
+}
@@ -193,7 +193,7 @@ impl NumberGuessContract {
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -293,17 +293,8 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
-
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance1 = guess1.abs_diff(winning_number);
+        let distance2 = guess2.abs_diff(winning_number);
 
         // Determine winner (if equal distance, player1 wins)
         let winner = if distance1 <= distance2 {
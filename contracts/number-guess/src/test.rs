@@ -176,7 +176,7 @@ fn test_complete_game() {
     assert_eq!(final_game.winner.unwrap(), winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
@@ -196,7 +196,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -244,16 +244,8 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
 
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
@@ -298,16 +290,8 @@ fn test_exact_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
     let expected_winner = if distance1 <= distance2 {
         player1.clone()
     } else {
@@ -174,8 +174,8 @@ fn test_complete_game_simple() {
     assert_eq!(game.player2_points, points);
     assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
     assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
 
     // Both players stick immediately (no hits)
     client.stick(&session_id, &player1);
@@ -207,11 +207,11 @@ fn test_initial_cards_dealt() {
     // Cards should be in valid range (1-13)
     for i in 0..game.player1_hand.len() {
         let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
     for i in 0..game.player2_hand.len() {
         let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
 }
 
@@ -227,8 +227,8 @@ fn test_get_hand_value() {
     let player2_value = client.get_hand_value(&session_id, &player2);
 
     // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((2..=20).contains(&player1_value));
+    assert!((2..=20).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
@@ -365,9 +365,9 @@ fn test_bust_detection() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
+            assert_eq!(winner, player2, "Player 2 should win when player 1 busts");
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -393,9 +393,9 @@ fn test_cannot_hit_after_bust() {
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if let Some(winner) = game.winner {
             did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
+            assert_eq!(winner, player2);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
             let result = client.try_hit(&session_id, &player1);
@@ -435,8 +435,8 @@ fn test_draw_starts_new_round() {
         // Verify new round was created
         let game_after = client.get_game(&session_id);
         assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
+        assert!(!game_after.player1_stuck); // Flags reset
+        assert!(!game_after.player2_stuck);
         assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
         assert_eq!(game_after.player2_hand.len(), 2);
     }
@@ -592,8 +592,8 @@ fn test_reveal_winner_idempotent() {
 
     // First reveal
     let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
+    if let Ok(winner1) = result1 {
+        let winner1 = winner1.unwrap();
 
         // Second reveal should return same winner (idempotent)
         let winner2 = client.reveal_winner(&session_id);
@@ -708,7 +708,7 @@ fn test_face_cards_worth_10() {
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
@@ -831,7 +831,10 @@ export function DeadDropGame({
           });
         }
 
-        const randomness = await getSessionRandomness(DEAD_DROP_PROVER_URL, roomCode);
+        const randomness = await getSessionRandomness(DEAD_DROP_PROVER_URL, roomCode, {
+          width: lobby.config.grid_width,
+          height: lobby.config.grid_height,
+        });
         if (DEAD_DROP_DEBUG) {
           console.info('[DeadDropUI][join_game] Randomness artifacts ready', {
             roomCode,
@@ -994,7 +997,10 @@ export function DeadDropGame({
             throw new Error('You are not a player in this game.');
           }
 
-          const randomness = await getSessionRandomness(DEAD_DROP_PROVER_URL, sessionId);
+          const randomness = await getSessionRandomness(DEAD_DROP_PROVER_URL, sessionId, {
+            width: onChainGame.config.grid_width,
+            height: onChainGame.config.grid_height,
+          });
           const onChainCommitmentHex = Buffer.from(onChainGame.drop_commitment).toString('hex').toLowerCase();
           if (randomness.dropCommitmentHex.toLowerCase() !== onChainCommitmentHex) {
             throw new Error(
@@ -1008,6 +1014,12 @@ export function DeadDropGame({
             turn: submittedTurn,
             pingX: selectedCell.x,
             pingY: selectedCell.y,
+            rules: {
+              toroidal: onChainGame.config.toroidal,
+              gridWidth: onChainGame.config.grid_width,
+              gridHeight: onChainGame.config.grid_height,
+              metric: onChainGame.config.metric,
+            },
           });
 
           const preSubmitGame = await deadDropService.getGame(sessionId);
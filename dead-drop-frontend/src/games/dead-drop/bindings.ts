@@ -35,17 +35,44 @@ if (typeof window !== "undefined") {
 
 
 export interface Game {
+  config: GameConfig;
   current_turn: u32;
   drop_commitment: Buffer;
   last_action_ledger: u32;
+  last_ping: LastPing;
   player1: string;
   player1_best_distance: u32;
+  player1_pings: u32;
   player1_points: i128;
+  player1_rounds: u32;
+  /**
+   * Sum of every ping distance this round; decides `SCORING_TOTAL` games.
+   */
+  player1_total_distance: u32;
   player2: string;
   player2_best_distance: u32;
+  player2_pings: u32;
   player2_points: i128;
+  player2_rounds: u32;
+  player2_total_distance: u32;
+  /**
+   * Turn undone by the last retract this round; it cannot be retracted again.
+   */
+  retracted_turn: Option<u32>;
+  /**
+   * Who pings on even turns this round (1 or 2); `whose_turn` must agree with `current_turn`'s parity.
+   */
+  round_opener: u32;
+  /**
+   * SHA-256 over the XDR-encoded `config`, so both clients can confirm the ruleset.
+   */
+  rules_hash: Buffer;
   status: GameStatus;
   whose_turn: u32;
+  /**
+   * `WinReason` as `u32`, set together with `winner`.
+   */
+  win_reason: Option<u32>;
   winner: Option<string>;
 }
 
@@ -64,31 +91,275 @@ export const Errors = {
   14: {message:"LobbyNotFound"},
   15: {message:"LobbyAlreadyExists"},
   16: {message:"SelfPlay"},
-  17: {message:"RandomnessVerificationFailed"}
+  17: {message:"RandomnessVerificationFailed"},
+  18: {message:"DistanceInputMismatch"},
+  19: {message:"PotTooLarge"},
+  20: {message:"RetractNotAllowed"},
+  21: {message:"VerifierCallFailed"},
+  22: {message:"NotInvited"},
+  23: {message:"LobbyExpired"},
+  24: {message:"Paused"},
+  25: {message:"ResumeHintTooLarge"},
+  26: {message:"PointsTooLarge"},
+  27: {message:"RefundRejected"}
 }
 
 
 export interface Lobby {
+  config: GameConfig;
   created_ledger: u32;
+  /**
+   * Private match: only this address may join. `None` lets anyone with the room code in.
+   */
+  expected_opponent: Option<string>;
   host: string;
   host_points: i128;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Lobby", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "VerifierId", values: void} | {tag: "RandomnessVerifierId", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Lobby", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "VerifierId", values: void} | {tag: "RandomnessVerifierId", values: void} | {tag: "FairnessProof", values: readonly [u32]} | {tag: "MaxPot", values: void} | {tag: "PlayerGames", values: readonly [string]} | {tag: "GameV2", values: readonly [u32]} | {tag: "LobbyV2", values: readonly [u32]} | {tag: "PingLog", values: readonly [u32]} | {tag: "Stats", values: readonly [string]} | {tag: "OpenLobbies", values: void} | {tag: "StrictProofs", values: void} | {tag: "PublicInputsVersion", values: void} | {tag: "Paused", values: void} | {tag: "ResumeHint", values: readonly [u32, string]};
+
+/**
+ * How a game ended, returned by `get_outcome`.
+ */
+export type Outcome = {tag: "InProgress", values: void} | {tag: "Win", values: readonly [string, WinReason]} | {tag: "Draw", values: void} | {tag: "Aborted", values: void};
+
+/**
+ * Everything a client needs to render a game's exact variant in one read.
+ */
+export interface RuleSet {
+  coin_flip_first_mover: boolean;
+  grid_height: u32;
+  grid_width: u32;
+  max_turns: u32;
+  /**
+   * `METRIC_MANHATTAN`, `METRIC_CHEBYSHEV` or `METRIC_EUCLIDEAN_SQ`.
+   */
+  metric: u32;
+  min_pings_before_win: u32;
+  player1_handicap: u32;
+  player1_wins_ties: boolean;
+  player2_handicap: u32;
+  retracts_allowed: boolean;
+  rounds_to_win: u32;
+  rules_hash: Buffer;
+  scoring_mode: u32;
+  timeout_ledgers: u32;
+  toroidal: boolean;
+}
+
+/**
+ * Undo information for the most recent ping, used by `retract_last_ping`.
+ */
+export interface LastPing {
+  ledger: u32;
+  pinger: u32;
+  previous_best: u32;
+}
+
+/**
+ * Why a game was won, recorded on `Game::win_reason` as its `u32` value.
+ */
+export enum WinReason {
+  FoundDrop = 0,
+  ClosestAtMaxTurns = 1,
+  Timeout = 2,
+  Concede = 3,
+  /**
+   * The loser's proof was rejected while strict proofs were on.
+   */
+  Forfeit = 4,
+}
+
+/**
+ * Per-game rule options chosen at creation.
+ */
+export interface GameConfig {
+  /**
+   * Pick the first pinger from `sha256(randomness_output || session_id)` instead of
+   * always letting player1 open.
+   */
+  coin_flip_first_mover: boolean;
+  /**
+   * Board height along y (10..=256). Bound into the proof's public inputs.
+   */
+  grid_height: u32;
+  /**
+   * Board width along x (10..=256). Bound into the proof's public inputs.
+   */
+  grid_width: u32;
+  /**
+   * Total turns across both players before the closest ping wins (even, <= 100).
+   */
+  max_turns: u32;
+  /**
+   * Distance metric: `METRIC_MANHATTAN` (`dx + dy`), `METRIC_CHEBYSHEV` (`max(dx, dy)`)
+   * or `METRIC_EUCLIDEAN_SQ` (`dx*dx + dy*dy`).
+   * Bound into the proof's public inputs.
+   */
+  metric: u32;
+  /**
+   * Pings a player must have made before finding the drop ends the game (0 = no minimum).
+   * An earlier distance-0 ping still records a best of 0 and wins once the threshold is met.
+   */
+  min_pings_before_win: u32;
+  /**
+   * Added to each seat's score when max turns decide the game (at most the max
+   * distance). Finding the drop still wins outright.
+   */
+  player1_handicap: u32;
+  /**
+   * Award equal max-turns scores to player1 instead of ending the game as a `Draw`.
+   */
+  player1_wins_ties: boolean;
+  player2_handicap: u32;
+  /**
+   * Casual mode: the last pinger may retract their ping until the opponent moves.
+   */
+  retracts_allowed: boolean;
+  /**
+   * Rounds a player must win to take the match (1 = single game, 2 = best-of-3, ...).
+   */
+  rounds_to_win: u32;
+  /**
+   * How max-turns games are decided: `SCORING_BEST` (closest single ping) or
+   * `SCORING_TOTAL` (lowest sum of all ping distances).
+   */
+  scoring_mode: u32;
+  /**
+   * Whether the board wraps at the edges (torus) or is bounded.
+   * Bound into the proof's public inputs so the circuit uses the same metric.
+   */
+  toroidal: boolean;
+  /**
+   * Shot clock: ledgers the player to move has before the opponent may claim a timeout.
+   */
+  turn_timeout_ledgers: u32;
+}
 
 export enum GameStatus {
   Created = 0,
   Active = 1,
   Completed = 2,
   Timeout = 3,
+  /**
+   * A best-of-N round finished; waiting for `next_round` to supply a new drop.
+   */
+  RoundOver = 4,
+  /**
+   * Max turns reached with equal best distances; no winner.
+   */
+  Draw = 5,
+  /**
+   * Unwound by the admin; stakes were refunded and there is no winner.
+   */
+  Aborted = 6,
+}
+
+/**
+ * The pre-`GameV2` game layout, as still stored under the legacy `Game(session_id)` key.
+ */
+export interface LegacyGame {
+  current_turn: u32;
+  drop_commitment: Buffer;
+  last_action_ledger: u32;
+  player1: string;
+  player1_best_distance: u32;
+  player1_points: i128;
+  player2: string;
+  player2_best_distance: u32;
+  player2_points: i128;
+  status: GameStatus;
+  whose_turn: u32;
+  winner: Option<string>;
+}
+
+/**
+ * One verified ping, as stored in the on-chain turn history.
+ */
+export interface PingRecord {
+  distance: u32;
+  ledger: u32;
+  player_is_one: boolean;
+  turn: u32;
+  x: u32;
+  y: u32;
+}
+
+/**
+ * The fields a list view needs, without commitments or stakes.
+ */
+export interface GameSummary {
+  current_turn: u32;
+  player1_best_distance: u32;
+  player2_best_distance: u32;
+  status: GameStatus;
+  whose_turn: u32;
+  winner: Option<string>;
 }
 
+/**
+ * The pre-`LobbyV2` lobby layout, as still stored under the legacy `Lobby(session_id)` key.
+ */
+export interface LegacyLobby {
+  created_ledger: u32;
+  host: string;
+  host_points: i128;
+}
+
+/**
+ * Lifetime record for a player across every session of this contract.
+ */
+export interface PlayerStats {
+  games: u32;
+  losses: u32;
+  /**
+   * Losses where the player was timed out (also counted in `losses`).
+   */
+  timeouts: u32;
+  wins: u32;
+}
+
+/**
+ * What this deployment supports, so clients can feature-detect before starting a game.
+ */
+export interface Capabilities {
+  max_grid_size: u32;
+  /**
+   * Distance metric ids accepted in `GameConfig` (`METRIC_MANHATTAN`, `METRIC_CHEBYSHEV`,
+   * `METRIC_EUCLIDEAN_SQ`).
+   */
+  supported_metrics: Buffer;
+  supports_risc0: boolean;
+  supports_ultrahonk: boolean;
+}
+
+/**
+ * Whatever currently occupies a session slot, returned by `get_session`.
+ */
+export type SessionState = {tag: "None", values: void} | {tag: "Lobby", values: readonly [Lobby]} | {tag: "Game", values: readonly [Game]};
+
 export interface Client {
+  /**
+   * Construct and simulate a concede transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Give up an active game. The opponent is recorded as the winner.
+   */
+  concede: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
+  /**
+   * Construct and simulate a rematch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replay a finished game between the same players, in the same session slot.
+   * 
+   * Both players must authorize their stake again. Seats are swapped so the
+   * previous loser becomes player1 and pings first; the new drop must come
+   * with fresh randomness artifacts.
+   */
+  rematch: ({session_id, requester, randomness_output, drop_commitment, randomness_signature}: {session_id: u32, requester: string, randomness_output: Buffer, drop_commitment: Buffer, randomness_signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -105,17 +376,48 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a bump_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Refresh the TTL of an unfinished game's storage without touching its state,
+   * so a long think cannot let the session expire.
+   */
+  bump_game: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
+  /**
+   * Construct and simulate a get_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Batch-fetch games for a monitoring page; `None` for ids with no game.
+   * Admin-only so the contract's storage can't be scraped wholesale.
+   */
+  get_games: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Option<Game>>>>
+
   /**
    * Construct and simulate a get_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Read-only lobby state query.
    */
   get_lobby: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Lobby>>>
 
+  /**
+   * Construct and simulate a get_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Full per-game ruleset, including contract-wide constants like the timeout.
+   */
+  get_rules: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RuleSet>>>
+
+  /**
+   * Construct and simulate a get_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lifetime results for a player; all zeros if they have never finished a game.
+   */
+  get_stats: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
   /**
    * Construct and simulate a join_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Join an existing lobby. Player 2 joins with the room code (session_id).
@@ -126,32 +428,100 @@ export interface Client {
   /**
    * Construct and simulate a open_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Open a lobby for a game session. Player 1 creates it with a room code (session_id).
-   * This is single-sig and does not require the opponent's address.
+   * This is single-sig and does not require the opponent's address, though the host
+   * may pin one with `expected_opponent` for a private match.
    */
-  open_game: ({session_id, host, host_points}: {session_id: u32, host: string, host_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  open_game: ({session_id, host, host_points, config, expected_opponent}: {session_id: u32, host: string, host_points: i128, config: GameConfig, expected_opponent: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a next_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start the next round of a best-of-N match with a freshly committed drop.
+   * 
+   * The randomness artifacts are verified exactly as at game start, and the
+   * previous round's commitment may not be reused. Coin-flip games draw the
+   * round's opener from the new randomness output. Only the player who did not
+   * open the finished round (`whose_turn` while `RoundOver`) may call it.
+   */
+  next_round: ({session_id, player, randomness_output, drop_commitment, randomness_signature}: {session_id: u32, player: string, randomness_output: Buffer, drop_commitment: Buffer, randomness_signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reap_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Delete a lobby nobody joined within `LOBBY_TTL_LEDGERS`. Callable by anyone.
+   */
+  reap_lobby: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Emergency switch: stop `open_game`, `join_game`, `start_game` and `rematch` while
+   * letting games already in flight play out.
+   */
+  set_paused: ({paused}: {paused: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Start a new game session between two players.
    * 
    * This is the legacy multi-sig flow where both players are known up-front.
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points, randomness_output, drop_commitment, randomness_signature}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, randomness_output: Buffer, drop_commitment: Buffer, randomness_signature: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points, randomness_output, drop_commitment, randomness_signature, config}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, randomness_output: Buffer, drop_commitment: Buffer, randomness_signature: Buffer, config: GameConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a admin_abort transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unwind a stuck game: no winner is recorded and the hub refunds both stakes.
+   */
+  admin_abort: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_max_pot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_max_pot: (options?: MethodOptions) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a get_outcome transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * How the game ended, so post-game screens need not combine `status` and `winner`.
+   */
+  get_outcome: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Outcome>>>
+
+  /**
+   * Construct and simulate a get_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Game or lobby for a session in one call, checking the game first.
+   * Returns `SessionState::None` instead of an error when the slot is empty.
+   */
+  get_session: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<SessionState>>
+
+  /**
+   * Construct and simulate a set_max_pot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cap the combined stake of a game. `None` removes the cap.
+   */
+  set_max_pot: ({max_pot}: {max_pot: Option<i128>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a submit_ping transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Submit a ping result with ZK proof verification (Noir + UltraHonk).
    * 
-   * Public inputs layout (6 x 32-byte big-endian field elements):
-   * [session_id, turn, ping_x, ping_y, drop_commitment, expected_distance]
+   * Public inputs layout (11 x 32-byte big-endian field elements):
+   * [version, session_id, turn, ping_x, ping_y, drop_commitment, expected_distance, toroidal, grid_width, grid_height, metric]
    */
   submit_ping: ({session_id, player, turn, distance, ping_x, ping_y, proof, public_inputs}: {session_id: u32, player: string, turn: u32, distance: u32, ping_x: u32, ping_y: u32, proof: Buffer, public_inputs: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
+  /**
+   * Construct and simulate a cancel_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel an open lobby so its session_id can be reused right away.
+   */
+  cancel_lobby: ({session_id, host}: {session_id: u32, host: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_ping_log transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verified pings so far, oldest first. Holds at most `max_turns` entries;
+   * in best-of-N matches older rounds roll off the front.
+   */
+  get_ping_log: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<PingRecord>>>>
+
   /**
    * Construct and simulate a set_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -159,10 +529,143 @@ export interface Client {
 
   /**
    * Construct and simulate a force_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Force a timeout win if the opponent has been AFK.
+   * Force a timeout win if the player to move has been AFK for the game's shot clock.
+   * Only the waiting player may claim it. Between rounds the player who owes
+   * `next_round` is the one on the clock.
    */
   force_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
+  /**
+   * Construct and simulate a migrate_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Convert legacy `Game(session_id)` entries to `GameV2` after an upgrade, instead of
+   * waiting for their next write. Returns how many sessions were migrated.
+   */
+  migrate_games: ({session_ids}: {session_ids: Array<u32>}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a current_player transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Address of the player to move, so clients don't have to map `whose_turn` themselves.
+   */
+  current_player: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a transfer_lobby transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand an unjoined lobby to a new host, who stakes `new_points` in place of the old host.
+   */
+  transfer_lobby: ({session_id, host, new_host, new_points}: {session_id: u32, host: string, new_host: string, new_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_first_mover transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Who pinged first in the current (or last finished) round: 1 = player1, 2 = player2.
+   * 
+   * This is the opener resolved when the round started. For coin-flip games it can be
+   * checked against the randomness output in the fairness proof.
+   */
+  get_first_mover: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_resume_hint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The player's own resume hint for a session, if one was stored.
+   */
+  get_resume_hint: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_turn_counts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ping counts for a game as `(total_pings, player1_pings, player2_pings)`.
+   * 
+   * Unlike `current_turn`, the total includes a final winning ping.
+   */
+  get_turn_counts: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [u32, u32, u32]>>>
+
+  /**
+   * Construct and simulate a set_resume_hint transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stash an opaque blob (typically the player's encrypted salt and coordinates) so a
+   * reloaded client can resume without a backend. Overwrites any previous hint.
+   */
+  set_resume_hint: ({session_id, player, hint}: {session_id: u32, player: string, hint: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_capabilities transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Static feature set of this build: grid limits, metrics and proof systems.
+   */
+  get_capabilities: (options?: MethodOptions) => Promise<AssembledTransaction<Capabilities>>
+
+  /**
+   * Construct and simulate a get_current_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Find the player's most recent game that has not finished yet.
+   */
+  get_current_game: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [u32, Game]>>>
+
+  /**
+   * Construct and simulate a get_game_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lightweight view of a game for leaderboards and lobby lists.
+   */
+  get_game_summary: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameSummary>>>
+
+  /**
+   * Construct and simulate a get_open_lobbies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session ids of lobbies waiting for an opponent, oldest first.
+   */
+  get_open_lobbies: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_strict_proofs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_strict_proofs: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a retract_last_ping transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Retract the caller's most recent ping in games that allow it.
+   * 
+   * Only valid before the opponent pings and within `RETRACT_WINDOW_LEDGERS`, and at
+   * most once per turn. Restores the turn, the pinger's ping count and their previous
+   * best distance.
+   */
+  retract_last_ping: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_strict_proofs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ranked mode: a rejected ping proof forfeits the game to the opponent. Off by default.
+   */
+  set_strict_proofs: ({enabled}: {enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_fairness_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Read the randomness artifacts that bound the drop commitment at game start.
+   * 
+   * Layout: `randomness_output (32) || drop_commitment (32) || randomness_signature (64)`.
+   * Auditors can split these bytes and re-feed them, together with `session_id`,
+   * to the randomness verifier's `verify_randomness`.
+   */
+  get_fairness_proof: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a get_committed_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Total points at risk for a session: both stakes for a game, or the host's
+   * stake for an open lobby. Points are committed via auth, not held by this contract.
+   */
+  get_committed_stake: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a get_remaining_turns transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Turns left before the game is decided by best distance (`max_turns - current_turn`).
+   */
+  get_remaining_turns: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a turns_until_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ledgers until the waiting player may call `force_timeout`; `0` once the clock has run out
+   * or when there is no such game. The deadline saturates at `u32::MAX`.
+   */
+  turns_until_timeout: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_num_public_inputs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Public input count `submit_ping` expects from the circuit. There is no setter: the
+   * count is fixed by the circuit layout, which `build_public_inputs` follows by index.
+   */
+  get_num_public_inputs: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a get_randomness_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -173,6 +676,18 @@ export interface Client {
    */
   set_randomness_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_public_inputs_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_public_inputs_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_public_inputs_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the layout version bound as the first public input. Proofs built against
+   * any other version fail with `InvalidPublicInputs`.
+   */
+  set_public_inputs_version: ({version}: {version: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
@@ -193,45 +708,133 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAMAAAAAAAAAAxjdXJyZW50X3R1cm4AAAAEAAAAAAAAAA9kcm9wX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAEmxhc3RfYWN0aW9uX2xlZGdlcgAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABVwbGF5ZXIxX2Jlc3RfZGlzdGFuY2UAAAAAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABVwbGF5ZXIyX2Jlc3RfZGlzdGFuY2UAAAAAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACkdhbWVTdGF0dXMAAAAAAAAAAAAKd2hvc2VfdHVybgAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAMAAAAAAAAAEUludmFsaWRHYW1lU3RhdHVzAAAAAAAABAAAAAAAAAALTm90WW91clR1cm4AAAAABgAAAAAAAAALSW52YWxpZFR1cm4AAAAABwAAAAAAAAATSW52YWxpZFB1YmxpY0lucHV0cwAAAAAIAAAAAAAAABdQcm9vZlZlcmlmaWNhdGlvbkZhaWxlZAAAAAAKAAAAAAAAABFUaW1lb3V0Tm90UmVhY2hlZAAAAAAAAAsAAAAAAAAAD0ludmFsaWREaXN0YW5jZQAAAAAMAAAAAAAAAA9NYXhUdXJuc1JlYWNoZWQAAAAADQAAAAAAAAANTG9iYnlOb3RGb3VuZAAAAAAAAA4AAAAAAAAAEkxvYmJ5QWxyZWFkeUV4aXN0cwAAAAAADwAAAAAAAAAIU2VsZlBsYXkAAAAQAAAAAAAAABxSYW5kb21uZXNzVmVyaWZpY2F0aW9uRmFpbGVkAAAAEQ==",
-        "AAAAAQAAAAAAAAAAAAAABUxvYmJ5AAAAAAAAAwAAAAAAAAAOY3JlYXRlZF9sZWRnZXIAAAAAAAQAAAAAAAAABGhvc3QAAAATAAAAAAAAAAtob3N0X3BvaW50cwAAAAAL",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABgAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAABUxvYmJ5AAAAAAAAAQAAAAQAAAAAAAAAAAAAAA5HYW1lSHViQWRkcmVzcwAAAAAAAAAAAAAAAAAFQWRtaW4AAAAAAAAAAAAAAAAAAApWZXJpZmllcklkAAAAAAAAAAAAAAAAABRSYW5kb21uZXNzVmVyaWZpZXJJZA==",
-        "AAAAAwAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAQAAAAAAAAAB0NyZWF0ZWQAAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAABAAAAAAAAAAlDb21wbGV0ZWQAAAAAAAACAAAAAAAAAAdUaW1lb3V0AAAAAAM=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAYAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAAAAAAxjdXJyZW50X3R1cm4AAAAEAAAAAAAAAA9kcm9wX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAEmxhc3RfYWN0aW9uX2xlZGdlcgAAAAAABAAAAAAAAAAJbGFzdF9waW5nAAAAAAAH0AAAAAhMYXN0UGluZwAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABVwbGF5ZXIxX2Jlc3RfZGlzdGFuY2UAAAAAAAAEAAAAAAAAAA1wbGF5ZXIxX3BpbmdzAAAAAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm91bmRzAAAAAAAEAAAARVN1bSBvZiBldmVyeSBwaW5nIGRpc3RhbmNlIHRoaXMgcm91bmQ7IGRlY2lkZXMgYFNDT1JJTkdfVE9UQUxgIGdhbWVzLgAAAAAAABZwbGF5ZXIxX3RvdGFsX2Rpc3RhbmNlAAAAAAAEAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAAFXBsYXllcjJfYmVzdF9kaXN0YW5jZQAAAAAAAAQAAAAAAAAADXBsYXllcjJfcGluZ3MAAAAAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9yb3VuZHMAAAAAAAQAAAAAAAAAFnBsYXllcjJfdG90YWxfZGlzdGFuY2UAAAAAAAQAAABJVHVybiB1bmRvbmUgYnkgdGhlIGxhc3QgcmV0cmFjdCB0aGlzIHJvdW5kOyBpdCBjYW5ub3QgYmUgcmV0cmFjdGVkIGFnYWluLgAAAAAAAA5yZXRyYWN0ZWRfdHVybgAAAAAD6AAAAAQAAABiV2hvIHBpbmdzIG9uIGV2ZW4gdHVybnMgdGhpcyByb3VuZCAoMSBvciAyKTsgYHdob3NlX3R1cm5gIG11c3QgYWdyZWUgd2l0aCBgY3VycmVudF90dXJuYCdzIHBhcml0eS4AAAAAAAxyb3VuZF9vcGVuZXIAAAAEAAAAT1NIQS0yNTYgb3ZlciB0aGUgWERSLWVuY29kZWQgYGNvbmZpZ2AsIHNvIGJvdGggY2xpZW50cyBjYW4gY29uZmlybSB0aGUgcnVsZXNldC4AAAAACnJ1bGVzX2hhc2gAAAAAA+4AAAAgAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAKR2FtZVN0YXR1cwAAAAAAAAAAAAp3aG9zZV90dXJuAAAAAAAEAAAAMWBXaW5SZWFzb25gIGFzIGB1MzJgLCBzZXQgdG9nZXRoZXIgd2l0aCBgd2lubmVyYC4AAAAAAAAKd2luX3JlYXNvbgAAAAAD6AAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAMAAAAAAAAAEUludmFsaWRHYW1lU3RhdHVzAAAAAAAABAAAAAAAAAALTm90WW91clR1cm4AAAAABgAAAAAAAAALSW52YWxpZFR1cm4AAAAABwAAAAAAAAATSW52YWxpZFB1YmxpY0lucHV0cwAAAAAIAAAAQ1RoZSBwcm9vZiB2ZXJpZmllciByZXR1cm5lZCBhIGNvbnRyYWN0IGVycm9yOiB0aGUgcHJvb2YgaXMgaW52YWxpZC4AAAAAF1Byb29mVmVyaWZpY2F0aW9uRmFpbGVkAAAAAAoAAAAAAAAAEVRpbWVvdXROb3RSZWFjaGVkAAAAAAAACwAAAAAAAAAPSW52YWxpZERpc3RhbmNlAAAAAAwAAAAAAAAAD01heFR1cm5zUmVhY2hlZAAAAAANAAAAAAAAAA1Mb2JieU5vdEZvdW5kAAAAAAAADgAAAAAAAAASTG9iYnlBbHJlYWR5RXhpc3RzAAAAAAAPAAAAAAAAAAhTZWxmUGxheQAAABAAAAB5VGhlIHJhbmRvbW5lc3MgdmVyaWZpZXIgZGlkIG5vdCByZXR1cm4gYHRydWVgLCBmb3Igd2hhdGV2ZXIgcmVhc29uIChyZWplY3Rpb24sCmNvbnRyYWN0IGVycm9yLCB0cmFwIG9yIG1pc3NpbmcgdmVyaWZpZXIpLgAAAAAAABxSYW5kb21uZXNzVmVyaWZpY2F0aW9uRmFpbGVkAAAAEQAAAAAAAAAVRGlzdGFuY2VJbnB1dE1pc21hdGNoAAAAAAAAEgAAAAAAAAALUG90VG9vTGFyZ2UAAAAAEwAAAAAAAAARUmV0cmFjdE5vdEFsbG93ZWQAAAAAAAAUAAAAmlRoZSBwcm9vZiB2ZXJpZmllciBnYXZlIG5vIGFuc3dlcjogaXQgdHJhcHBlZCwgb3IgdGhlIGFkZHJlc3Mgb3IgZnVuY3Rpb24gaXMKbWlzc2luZy4gVGhlIHByb29mIG1heSBzdGlsbCBiZSBpbnZhbGlkIGlmIHRoZSB2ZXJpZmllciByZWplY3RzIGJ5IHBhbmlja2luZy4AAAAAABJWZXJpZmllckNhbGxGYWlsZWQAAAAAABUAAAAAAAAACk5vdEludml0ZWQAAAAAABYAAAAAAAAADExvYmJ5RXhwaXJlZAAAABcAAAAAAAAABlBhdXNlZAAAAAAAGAAAAAAAAAASUmVzdW1lSGludFRvb0xhcmdlAAAAAAAZAAAAAAAAAA5Qb2ludHNUb29MYXJnZQAAAAAAGgAAAE9UaGUgaHViJ3MgYHJlZnVuZF9nYW1lYCByZXR1cm5lZCBhIGNvbnRyYWN0IGVycm9yLCBzbyB0aGUgZ2FtZSB3YXMgbm90IGFib3J0ZWQuAAAAAA5SZWZ1bmRSZWplY3RlZAAAAAAAGw==",
+        "AAAAAQAAAAAAAAAAAAAABUxvYmJ5AAAAAAAABQAAAAAAAAAGY29uZmlnAAAAAAfQAAAACkdhbWVDb25maWcAAAAAAAAAAAAOY3JlYXRlZF9sZWRnZXIAAAAAAAQAAABUUHJpdmF0ZSBtYXRjaDogb25seSB0aGlzIGFkZHJlc3MgbWF5IGpvaW4uIGBOb25lYCBsZXRzIGFueW9uZSB3aXRoIHRoZSByb29tIGNvZGUgaW4uAAAAEWV4cGVjdGVkX29wcG9uZW50AAAAAAAD6AAAABMAAAAAAAAABGhvc3QAAAATAAAAAAAAAAtob3N0X3BvaW50cwAAAAAL",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEgAAAAEAAABLTGVnYWN5IGdhbWUga2V5OyByZWFkIGFzIGEgZmFsbGJhY2sgYW5kIG1pZ3JhdGVkIHRvIGBHYW1lVjJgIG9uIG5leHQgd3JpdGUuAAAAAARHYW1lAAAAAQAAAAQAAAABAAAAAAAAAAVMb2JieQAAAAAAAAEAAAAEAAAAAAAAAAAAAAAOR2FtZUh1YkFkZHJlc3MAAAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAKVmVyaWZpZXJJZAAAAAAAAAAAAAAAAAAUUmFuZG9tbmVzc1ZlcmlmaWVySWQAAAABAAAAAAAAAA1GYWlybmVzc1Byb29mAAAAAAAAAQAAAAQAAAAAAAAAAAAAAAZNYXhQb3QAAAAAAAEAAAAAAAAAC1BsYXllckdhbWVzAAAAAAEAAAATAAAAAQAAAAAAAAAGR2FtZVYyAAAAAAABAAAABAAAAAEAAAAAAAAAB0xvYmJ5VjIAAAAAAQAAAAQAAAABAAAAAAAAAAdQaW5nTG9nAAAAAAEAAAAEAAAAAQAAAAAAAAAFU3RhdHMAAAAAAAABAAAAEwAAAAAAAAAAAAAAC09wZW5Mb2JiaWVzAAAAAAAAAAAAAAAADFN0cmljdFByb29mcwAAAAAAAAAAAAAAE1B1YmxpY0lucHV0c1ZlcnNpb24AAAAAAAAAAAAAAAAGUGF1c2VkAAAAAAABAAAATU9wYXF1ZSBjbGllbnQgYmxvYiBmb3IgcmVjb25uZWN0aW5nIHRvIGEgc2Vzc2lvbjsgbmV2ZXIgaW50ZXJwcmV0ZWQgb24tY2hhaW4uAAAAAAAAClJlc3VtZUhpbnQAAAAAAAIAAAAEAAAAEw==",
+        "AAAAAgAAACxIb3cgYSBnYW1lIGVuZGVkLCByZXR1cm5lZCBieSBgZ2V0X291dGNvbWVgLgAAAAAAAAAHT3V0Y29tZQAAAAAEAAAAAAAAADdTdGlsbCBiZWluZyBwbGF5ZWQsIGluY2x1ZGluZyBiZXR3ZWVuIGJlc3Qtb2YtTiByb3VuZHMuAAAAAApJblByb2dyZXNzAAAAAAABAAAAAAAAAANXaW4AAAAAAgAAABMAAAfQAAAACVdpblJlYXNvbgAAAAAAAAAAAAAAAAAABERyYXcAAAAAAAAAKlVud291bmQgYnkgdGhlIGFkbWluIHdpdGggc3Rha2VzIHJlZnVuZGVkLgAAAAAAB0Fib3J0ZWQA",
+        "AAAAAQAAAEdFdmVyeXRoaW5nIGEgY2xpZW50IG5lZWRzIHRvIHJlbmRlciBhIGdhbWUncyBleGFjdCB2YXJpYW50IGluIG9uZSByZWFkLgAAAAAAAAAAB1J1bGVTZXQAAAAADwAAAAAAAAAVY29pbl9mbGlwX2ZpcnN0X21vdmVyAAAAAAAAAQAAAAAAAAALZ3JpZF9oZWlnaHQAAAAABAAAAAAAAAAKZ3JpZF93aWR0aAAAAAAABAAAAAAAAAAJbWF4X3R1cm5zAAAAAAAABAAAAEBgTUVUUklDX01BTkhBVFRBTmAsIGBNRVRSSUNfQ0hFQllTSEVWYCBvciBgTUVUUklDX0VVQ0xJREVBTl9TUWAuAAAABm1ldHJpYwAAAAAABAAAAAAAAAAUbWluX3BpbmdzX2JlZm9yZV93aW4AAAAEAAAAAAAAABBwbGF5ZXIxX2hhbmRpY2FwAAAABAAAAAAAAAARcGxheWVyMV93aW5zX3RpZXMAAAAAAAABAAAAAAAAABBwbGF5ZXIyX2hhbmRpY2FwAAAABAAAAAAAAAAQcmV0cmFjdHNfYWxsb3dlZAAAAAEAAAAAAAAADXJvdW5kc190b193aW4AAAAAAAAEAAAAAAAAAApydWxlc19oYXNoAAAAAAPuAAAAIAAAAAAAAAAMc2NvcmluZ19tb2RlAAAABAAAAAAAAAAPdGltZW91dF9sZWRnZXJzAAAAAAQAAAAAAAAACHRvcm9pZGFsAAAAAQ==",
+        "AAAAAQAAAEdVbmRvIGluZm9ybWF0aW9uIGZvciB0aGUgbW9zdCByZWNlbnQgcGluZywgdXNlZCBieSBgcmV0cmFjdF9sYXN0X3BpbmdgLgAAAAAAAAAACExhc3RQaW5nAAAAAwAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAZwaW5nZXIAAAAAAAQAAAAAAAAADXByZXZpb3VzX2Jlc3QAAAAAAAAE",
+        "AAAAAwAAAEZXaHkgYSBnYW1lIHdhcyB3b24sIHJlY29yZGVkIG9uIGBHYW1lOjp3aW5fcmVhc29uYCBhcyBpdHMgYHUzMmAgdmFsdWUuAAAAAAAAAAAACVdpblJlYXNvbgAAAAAAAAUAAAAAAAAACUZvdW5kRHJvcAAAAAAAAAAAAAAAAAAAEUNsb3Nlc3RBdE1heFR1cm5zAAAAAAAAAQAAAAAAAAAHVGltZW91dAAAAAACAAAAAAAAAAdDb25jZWRlAAAAAAMAAAA7VGhlIGxvc2VyJ3MgcHJvb2Ygd2FzIHJlamVjdGVkIHdoaWxlIHN0cmljdCBwcm9vZnMgd2VyZSBvbi4AAAAAB0ZvcmZlaXQAAAAABA==",
+        "AAAAAQAAAClQZXItZ2FtZSBydWxlIG9wdGlvbnMgY2hvc2VuIGF0IGNyZWF0aW9uLgAAAAAAAAAAAAAKR2FtZUNvbmZpZwAAAAAADgAAAGxQaWNrIHRoZSBmaXJzdCBwaW5nZXIgZnJvbSBgc2hhMjU2KHJhbmRvbW5lc3Nfb3V0cHV0IHx8IHNlc3Npb25faWQpYCBpbnN0ZWFkIG9mCmFsd2F5cyBsZXR0aW5nIHBsYXllcjEgb3Blbi4AAAAVY29pbl9mbGlwX2ZpcnN0X21vdmVyAAAAAAAAAQAAAEZCb2FyZCBoZWlnaHQgYWxvbmcgeSAoMTAuLj0yNTYpLiBCb3VuZCBpbnRvIHRoZSBwcm9vZidzIHB1YmxpYyBpbnB1dHMuAAAAAAALZ3JpZF9oZWlnaHQAAAAABAAAAEVCb2FyZCB3aWR0aCBhbG9uZyB4ICgxMC4uPTI1NikuIEJvdW5kIGludG8gdGhlIHByb29mJ3MgcHVibGljIGlucHV0cy4AAAAAAAAKZ3JpZF93aWR0aAAAAAAABAAAAExUb3RhbCB0dXJucyBhY3Jvc3MgYm90aCBwbGF5ZXJzIGJlZm9yZSB0aGUgY2xvc2VzdCBwaW5nIHdpbnMgKGV2ZW4sIDw9IDEwMCkuAAAACW1heF90dXJucwAAAAAAAAQAAAClRGlzdGFuY2UgbWV0cmljOiBgTUVUUklDX01BTkhBVFRBTmAgKGBkeCArIGR5YCksIGBNRVRSSUNfQ0hFQllTSEVWYCAoYG1heChkeCwgZHkpYCkKb3IgYE1FVFJJQ19FVUNMSURFQU5fU1FgIChgZHgqZHggKyBkeSpkeWApLgpCb3VuZCBpbnRvIHRoZSBwcm9vZidzIHB1YmxpYyBpbnB1dHMuAAAAAAAABm1ldHJpYwAAAAAABAAAAK5QaW5ncyBhIHBsYXllciBtdXN0IGhhdmUgbWFkZSBiZWZvcmUgZmluZGluZyB0aGUgZHJvcCBlbmRzIHRoZSBnYW1lICgwID0gbm8gbWluaW11bSkuCkFuIGVhcmxpZXIgZGlzdGFuY2UtMCBwaW5nIHN0aWxsIHJlY29yZHMgYSBiZXN0IG9mIDAgYW5kIHdpbnMgb25jZSB0aGUgdGhyZXNob2xkIGlzIG1ldC4AAAAAABRtaW5fcGluZ3NfYmVmb3JlX3dpbgAAAAQAAAB7QWRkZWQgdG8gZWFjaCBzZWF0J3Mgc2NvcmUgd2hlbiBtYXggdHVybnMgZGVjaWRlIHRoZSBnYW1lIChhdCBtb3N0IHRoZSBtYXgKZGlzdGFuY2UpLiBGaW5kaW5nIHRoZSBkcm9wIHN0aWxsIHdpbnMgb3V0cmlnaHQuAAAAABBwbGF5ZXIxX2hhbmRpY2FwAAAABAAAAE9Bd2FyZCBlcXVhbCBtYXgtdHVybnMgc2NvcmVzIHRvIHBsYXllcjEgaW5zdGVhZCBvZiBlbmRpbmcgdGhlIGdhbWUgYXMgYSBgRHJhd2AuAAAAABFwbGF5ZXIxX3dpbnNfdGllcwAAAAAAAAEAAAAAAAAAEHBsYXllcjJfaGFuZGljYXAAAAAEAAAATUNhc3VhbCBtb2RlOiB0aGUgbGFzdCBwaW5nZXIgbWF5IHJldHJhY3QgdGhlaXIgcGluZyB1bnRpbCB0aGUgb3Bwb25lbnQgbW92ZXMuAAAAAAAAEHJldHJhY3RzX2FsbG93ZWQAAAABAAAAUVJvdW5kcyBhIHBsYXllciBtdXN0IHdpbiB0byB0YWtlIHRoZSBtYXRjaCAoMSA9IHNpbmdsZSBnYW1lLCAyID0gYmVzdC1vZi0zLCAuLi4pLgAAAAAAAA1yb3VuZHNfdG9fd2luAAAAAAAABAAAAHxIb3cgbWF4LXR1cm5zIGdhbWVzIGFyZSBkZWNpZGVkOiBgU0NPUklOR19CRVNUYCAoY2xvc2VzdCBzaW5nbGUgcGluZykgb3IKYFNDT1JJTkdfVE9UQUxgIChsb3dlc3Qgc3VtIG9mIGFsbCBwaW5nIGRpc3RhbmNlcykuAAAADHNjb3JpbmdfbW9kZQAAAAQAAACFV2hldGhlciB0aGUgYm9hcmQgd3JhcHMgYXQgdGhlIGVkZ2VzICh0b3J1cykgb3IgaXMgYm91bmRlZC4KQm91bmQgaW50byB0aGUgcHJvb2YncyBwdWJsaWMgaW5wdXRzIHNvIHRoZSBjaXJjdWl0IHVzZXMgdGhlIHNhbWUgbWV0cmljLgAAAAAAAAh0b3JvaWRhbAAAAAEAAABTU2hvdCBjbG9jazogbGVkZ2VycyB0aGUgcGxheWVyIHRvIG1vdmUgaGFzIGJlZm9yZSB0aGUgb3Bwb25lbnQgbWF5IGNsYWltIGEgdGltZW91dC4AAAAAFHR1cm5fdGltZW91dF9sZWRnZXJzAAAABA==",
+        "AAAAAwAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAcAAAAAAAAAB0NyZWF0ZWQAAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAABAAAAAAAAAAlDb21wbGV0ZWQAAAAAAAACAAAAAAAAAAdUaW1lb3V0AAAAAAMAAABKQSBiZXN0LW9mLU4gcm91bmQgZmluaXNoZWQ7IHdhaXRpbmcgZm9yIGBuZXh0X3JvdW5kYCB0byBzdXBwbHkgYSBuZXcgZHJvcC4AAAAAAAlSb3VuZE92ZXIAAAAAAAAEAAAAN01heCB0dXJucyByZWFjaGVkIHdpdGggZXF1YWwgYmVzdCBkaXN0YW5jZXM7IG5vIHdpbm5lci4AAAAABERyYXcAAAAFAAAAQlVud291bmQgYnkgdGhlIGFkbWluOyBzdGFrZXMgd2VyZSByZWZ1bmRlZCBhbmQgdGhlcmUgaXMgbm8gd2lubmVyLgAAAAAAB0Fib3J0ZWQAAAAABg==",
+        "AAAAAQAAAFZUaGUgcHJlLWBHYW1lVjJgIGdhbWUgbGF5b3V0LCBhcyBzdGlsbCBzdG9yZWQgdW5kZXIgdGhlIGxlZ2FjeSBgR2FtZShzZXNzaW9uX2lkKWAga2V5LgAAAAAAAAAAAApMZWdhY3lHYW1lAAAAAAAMAAAAAAAAAAxjdXJyZW50X3R1cm4AAAAEAAAAAAAAAA9kcm9wX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAEmxhc3RfYWN0aW9uX2xlZGdlcgAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABVwbGF5ZXIxX2Jlc3RfZGlzdGFuY2UAAAAAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABVwbGF5ZXIyX2Jlc3RfZGlzdGFuY2UAAAAAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACkdhbWVTdGF0dXMAAAAAAAAAAAAKd2hvc2VfdHVybgAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAQAAADpPbmUgdmVyaWZpZWQgcGluZywgYXMgc3RvcmVkIGluIHRoZSBvbi1jaGFpbiB0dXJuIGhpc3RvcnkuAAAAAAAAAAAAClBpbmdSZWNvcmQAAAAAAAYAAAAAAAAACGRpc3RhbmNlAAAABAAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAA1wbGF5ZXJfaXNfb25lAAAAAAAAAQAAAAAAAAAEdHVybgAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABA==",
+        "AAAAAQAAADxUaGUgZmllbGRzIGEgbGlzdCB2aWV3IG5lZWRzLCB3aXRob3V0IGNvbW1pdG1lbnRzIG9yIHN0YWtlcy4AAAAAAAAAC0dhbWVTdW1tYXJ5AAAAAAYAAAAAAAAADGN1cnJlbnRfdHVybgAAAAQAAAAAAAAAFXBsYXllcjFfYmVzdF9kaXN0YW5jZQAAAAAAAAQAAAAAAAAAFXBsYXllcjJfYmVzdF9kaXN0YW5jZQAAAAAAAAQAAAAAAAAABnN0YXR1cwAAAAAH0AAAAApHYW1lU3RhdHVzAAAAAAAAAAAACndob3NlX3R1cm4AAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAFlUaGUgcHJlLWBMb2JieVYyYCBsb2JieSBsYXlvdXQsIGFzIHN0aWxsIHN0b3JlZCB1bmRlciB0aGUgbGVnYWN5IGBMb2JieShzZXNzaW9uX2lkKWAga2V5LgAAAAAAAAAAAAALTGVnYWN5TG9iYnkAAAAAAwAAAAAAAAAOY3JlYXRlZF9sZWRnZXIAAAAAAAQAAAAAAAAABGhvc3QAAAATAAAAAAAAAAtob3N0X3BvaW50cwAAAAAL",
+        "AAAAAQAAAENMaWZldGltZSByZWNvcmQgZm9yIGEgcGxheWVyIGFjcm9zcyBldmVyeSBzZXNzaW9uIG9mIHRoaXMgY29udHJhY3QuAAAAAAAAAAALUGxheWVyU3RhdHMAAAAABAAAAAAAAAAFZ2FtZXMAAAAAAAAEAAAAAAAAAAZsb3NzZXMAAAAAAAQAAABBTG9zc2VzIHdoZXJlIHRoZSBwbGF5ZXIgd2FzIHRpbWVkIG91dCAoYWxzbyBjb3VudGVkIGluIGBsb3NzZXNgKS4AAAAAAAAIdGltZW91dHMAAAAEAAAAAAAAAAR3aW5zAAAABA==",
+        "AAAAAQAAAFRXaGF0IHRoaXMgZGVwbG95bWVudCBzdXBwb3J0cywgc28gY2xpZW50cyBjYW4gZmVhdHVyZS1kZXRlY3QgYmVmb3JlIHN0YXJ0aW5nIGEgZ2FtZS4AAAAAAAAADENhcGFiaWxpdGllcwAAAAQAAAAAAAAADW1heF9ncmlkX3NpemUAAAAAAAAEAAAAbURpc3RhbmNlIG1ldHJpYyBpZHMgYWNjZXB0ZWQgaW4gYEdhbWVDb25maWdgIChgTUVUUklDX01BTkhBVFRBTmAsIGBNRVRSSUNfQ0hFQllTSEVWYCwKYE1FVFJJQ19FVUNMSURFQU5fU1FgKS4AAAAAAAARc3VwcG9ydGVkX21ldHJpY3MAAAAAAAAOAAAAAAAAAA5zdXBwb3J0c19yaXNjMAAAAAAAAQAAAAAAAAASc3VwcG9ydHNfdWx0cmFob25rAAAAAAAB",
+        "AAAAAgAAAEZXaGF0ZXZlciBjdXJyZW50bHkgb2NjdXBpZXMgYSBzZXNzaW9uIHNsb3QsIHJldHVybmVkIGJ5IGBnZXRfc2Vzc2lvbmAuAAAAAAAAAAAADFNlc3Npb25TdGF0ZQAAAAMAAAAAAAAAAAAAAAROb25lAAAAAQAAAAAAAAAFTG9iYnkAAAAAAAABAAAH0AAAAAVMb2JieQAAAAAAAAEAAAAAAAAABEdhbWUAAAABAAAH0AAAAARHYW1l",
+        "AAAAAAAAAD9HaXZlIHVwIGFuIGFjdGl2ZSBnYW1lLiBUaGUgb3Bwb25lbnQgaXMgcmVjb3JkZWQgYXMgdGhlIHdpbm5lci4AAAAAB2NvbmNlZGUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
         "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAPtSZXBsYXkgYSBmaW5pc2hlZCBnYW1lIGJldHdlZW4gdGhlIHNhbWUgcGxheWVycywgaW4gdGhlIHNhbWUgc2Vzc2lvbiBzbG90LgoKQm90aCBwbGF5ZXJzIG11c3QgYXV0aG9yaXplIHRoZWlyIHN0YWtlIGFnYWluLiBTZWF0cyBhcmUgc3dhcHBlZCBzbyB0aGUKcHJldmlvdXMgbG9zZXIgYmVjb21lcyBwbGF5ZXIxIGFuZCBwaW5ncyBmaXJzdDsgdGhlIG5ldyBkcm9wIG11c3QgY29tZQp3aXRoIGZyZXNoIHJhbmRvbW5lc3MgYXJ0aWZhY3RzLgAAAAAHcmVtYXRjaAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAlyZXF1ZXN0ZXIAAAAAAAATAAAAAAAAABFyYW5kb21uZXNzX291dHB1dAAAAAAAA+4AAAAgAAAAAAAAAA9kcm9wX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAFHJhbmRvbW5lc3Nfc2lnbmF0dXJlAAAD7gAAAEAAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
         "AAAAAAAAABtSZWFkLW9ubHkgZ2FtZSBzdGF0ZSBxdWVyeS4AAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAHpSZWZyZXNoIHRoZSBUVEwgb2YgYW4gdW5maW5pc2hlZCBnYW1lJ3Mgc3RvcmFnZSB3aXRob3V0IHRvdWNoaW5nIGl0cyBzdGF0ZSwKc28gYSBsb25nIHRoaW5rIGNhbm5vdCBsZXQgdGhlIHNlc3Npb24gZXhwaXJlLgAAAAAACWJ1bXBfZ2FtZQAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAIZCYXRjaC1mZXRjaCBnYW1lcyBmb3IgYSBtb25pdG9yaW5nIHBhZ2U7IGBOb25lYCBmb3IgaWRzIHdpdGggbm8gZ2FtZS4KQWRtaW4tb25seSBzbyB0aGUgY29udHJhY3QncyBzdG9yYWdlIGNhbid0IGJlIHNjcmFwZWQgd2hvbGVzYWxlLgAAAAAACWdldF9nYW1lcwAAAAAAAAEAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAQAAA+oAAAPoAAAH0AAAAARHYW1l",
         "AAAAAAAAABxSZWFkLW9ubHkgbG9iYnkgc3RhdGUgcXVlcnkuAAAACWdldF9sb2JieQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAFTG9iYnkAAAAAAAAD",
+        "AAAAAAAAAEpGdWxsIHBlci1nYW1lIHJ1bGVzZXQsIGluY2x1ZGluZyBjb250cmFjdC13aWRlIGNvbnN0YW50cyBsaWtlIHRoZSB0aW1lb3V0LgAAAAAACWdldF9ydWxlcwAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAHUnVsZVNldAAAAAAD",
+        "AAAAAAAAAExMaWZldGltZSByZXN1bHRzIGZvciBhIHBsYXllcjsgYWxsIHplcm9zIGlmIHRoZXkgaGF2ZSBuZXZlciBmaW5pc2hlZCBhIGdhbWUuAAAACWdldF9zdGF0cwAAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAAC1BsYXllclN0YXRzAA==",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
         "AAAAAAAAAIBKb2luIGFuIGV4aXN0aW5nIGxvYmJ5LiBQbGF5ZXIgMiBqb2lucyB3aXRoIHRoZSByb29tIGNvZGUgKHNlc3Npb25faWQpLgpUaGlzIGlzIHNpbmdsZS1zaWcgYW5kIGNhbGxzIEdhbWUgSHViIHRvIHN0YXJ0IHRoZSBnYW1lLgAAAAlqb2luX2dhbWUAAAAAAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZqb2luZXIAAAAAABMAAAAAAAAADWpvaW5lcl9wb2ludHMAAAAAAAALAAAAAAAAABFyYW5kb21uZXNzX291dHB1dAAAAAAAA+4AAAAgAAAAAAAAAA9kcm9wX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAFHJhbmRvbW5lc3Nfc2lnbmF0dXJlAAAD7gAAAEAAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAJNPcGVuIGEgbG9iYnkgZm9yIGEgZ2FtZSBzZXNzaW9uLiBQbGF5ZXIgMSBjcmVhdGVzIGl0IHdpdGggYSByb29tIGNvZGUgKHNlc3Npb25faWQpLgpUaGlzIGlzIHNpbmdsZS1zaWcgYW5kIGRvZXMgbm90IHJlcXVpcmUgdGhlIG9wcG9uZW50J3MgYWRkcmVzcy4AAAAACW9wZW5fZ2FtZQAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABGhvc3QAAAATAAAAAAAAAAtob3N0X3BvaW50cwAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAN1PcGVuIGEgbG9iYnkgZm9yIGEgZ2FtZSBzZXNzaW9uLiBQbGF5ZXIgMSBjcmVhdGVzIGl0IHdpdGggYSByb29tIGNvZGUgKHNlc3Npb25faWQpLgpUaGlzIGlzIHNpbmdsZS1zaWcgYW5kIGRvZXMgbm90IHJlcXVpcmUgdGhlIG9wcG9uZW50J3MgYWRkcmVzcywgdGhvdWdoIHRoZSBob3N0Cm1heSBwaW4gb25lIHdpdGggYGV4cGVjdGVkX29wcG9uZW50YCBmb3IgYSBwcml2YXRlIG1hdGNoLgAAAAAAAAlvcGVuX2dhbWUAAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARob3N0AAAAEwAAAAAAAAALaG9zdF9wb2ludHMAAAAACwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACkdhbWVDb25maWcAAAAAAAAAAAARZXhwZWN0ZWRfb3Bwb25lbnQAAAAAAAPoAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAHdTdGFydCBhIG5ldyBnYW1lIHNlc3Npb24gYmV0d2VlbiB0d28gcGxheWVycy4KClRoaXMgaXMgdGhlIGxlZ2FjeSBtdWx0aS1zaWcgZmxvdyB3aGVyZSBib3RoIHBsYXllcnMgYXJlIGtub3duIHVwLWZyb250LgAAAAAKc3RhcnRfZ2FtZQAAAAAACAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAARcmFuZG9tbmVzc19vdXRwdXQAAAAAAAPuAAAAIAAAAAAAAAAPZHJvcF9jb21taXRtZW50AAAAA+4AAAAgAAAAAAAAABRyYW5kb21uZXNzX3NpZ25hdHVyZQAAA+4AAABAAAAAAQAAA+kAAAACAAAAAw==",
-        "AAAAAAAAAMlTdWJtaXQgYSBwaW5nIHJlc3VsdCB3aXRoIFpLIHByb29mIHZlcmlmaWNhdGlvbiAoTm9pciArIFVsdHJhSG9uaykuCgpQdWJsaWMgaW5wdXRzIGxheW91dCAoNiB4IDMyLWJ5dGUgYmlnLWVuZGlhbiBmaWVsZCBlbGVtZW50cyk6CltzZXNzaW9uX2lkLCB0dXJuLCBwaW5nX3gsIHBpbmdfeSwgZHJvcF9jb21taXRtZW50LCBleHBlY3RlZF9kaXN0YW5jZV0AAAAAAAALc3VibWl0X3BpbmcAAAAACAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAR0dXJuAAAABAAAAAAAAAAIZGlzdGFuY2UAAAAEAAAAAAAAAAZwaW5nX3gAAAAAAAQAAAAAAAAABnBpbmdfeQAAAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAAAAAA1wdWJsaWNfaW5wdXRzAAAAAAAD6gAAA+4AAAAgAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAAWpTdGFydCB0aGUgbmV4dCByb3VuZCBvZiBhIGJlc3Qtb2YtTiBtYXRjaCB3aXRoIGEgZnJlc2hseSBjb21taXR0ZWQgZHJvcC4KClRoZSByYW5kb21uZXNzIGFydGlmYWN0cyBhcmUgdmVyaWZpZWQgZXhhY3RseSBhcyBhdCBnYW1lIHN0YXJ0LCBhbmQgdGhlCnByZXZpb3VzIHJvdW5kJ3MgY29tbWl0bWVudCBtYXkgbm90IGJlIHJldXNlZC4gQ29pbi1mbGlwIGdhbWVzIGRyYXcgdGhlCnJvdW5kJ3Mgb3BlbmVyIGZyb20gdGhlIG5ldyByYW5kb21uZXNzIG91dHB1dC4gT25seSB0aGUgcGxheWVyIHdobyBkaWQgbm90Cm9wZW4gdGhlIGZpbmlzaGVkIHJvdW5kIChgd2hvc2VfdHVybmAgd2hpbGUgYFJvdW5kT3ZlcmApIG1heSBjYWxsIGl0LgAAAAAACm5leHRfcm91bmQAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAARcmFuZG9tbmVzc19vdXRwdXQAAAAAAAPuAAAAIAAAAAAAAAAPZHJvcF9jb21taXRtZW50AAAAA+4AAAAgAAAAAAAAABRyYW5kb21uZXNzX3NpZ25hdHVyZQAAA+4AAABAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAExEZWxldGUgYSBsb2JieSBub2JvZHkgam9pbmVkIHdpdGhpbiBgTE9CQllfVFRMX0xFREdFUlNgLiBDYWxsYWJsZSBieSBhbnlvbmUuAAAACnJlYXBfbG9iYnkAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHtFbWVyZ2VuY3kgc3dpdGNoOiBzdG9wIGBvcGVuX2dhbWVgLCBgam9pbl9nYW1lYCwgYHN0YXJ0X2dhbWVgIGFuZCBgcmVtYXRjaGAgd2hpbGUKbGV0dGluZyBnYW1lcyBhbHJlYWR5IGluIGZsaWdodCBwbGF5IG91dC4AAAAACnNldF9wYXVzZWQAAAAAAAEAAAAAAAAABnBhdXNlZAAAAAAAAQAAAAA=",
+        "AAAAAAAAAHdTdGFydCBhIG5ldyBnYW1lIHNlc3Npb24gYmV0d2VlbiB0d28gcGxheWVycy4KClRoaXMgaXMgdGhlIGxlZ2FjeSBtdWx0aS1zaWcgZmxvdyB3aGVyZSBib3RoIHBsYXllcnMgYXJlIGtub3duIHVwLWZyb250LgAAAAAKc3RhcnRfZ2FtZQAAAAAACQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAARcmFuZG9tbmVzc19vdXRwdXQAAAAAAAPuAAAAIAAAAAAAAAAPZHJvcF9jb21taXRtZW50AAAAA+4AAAAgAAAAAAAAABRyYW5kb21uZXNzX3NpZ25hdHVyZQAAA+4AAABAAAAAAAAAAAZjb25maWcAAAAAB9AAAAAKR2FtZUNvbmZpZwAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAEtVbndpbmQgYSBzdHVjayBnYW1lOiBubyB3aW5uZXIgaXMgcmVjb3JkZWQgYW5kIHRoZSBodWIgcmVmdW5kcyBib3RoIHN0YWtlcy4AAAAAC2FkbWluX2Fib3J0AAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAALZ2V0X21heF9wb3QAAAAAAAAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAFBIb3cgdGhlIGdhbWUgZW5kZWQsIHNvIHBvc3QtZ2FtZSBzY3JlZW5zIG5lZWQgbm90IGNvbWJpbmUgYHN0YXR1c2AgYW5kIGB3aW5uZXJgLgAAAAtnZXRfb3V0Y29tZQAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAAB091dGNvbWUAAAAAAw==",
+        "AAAAAAAAAIpHYW1lIG9yIGxvYmJ5IGZvciBhIHNlc3Npb24gaW4gb25lIGNhbGwsIGNoZWNraW5nIHRoZSBnYW1lIGZpcnN0LgpSZXR1cm5zIGBTZXNzaW9uU3RhdGU6Ok5vbmVgIGluc3RlYWQgb2YgYW4gZXJyb3Igd2hlbiB0aGUgc2xvdCBpcyBlbXB0eS4AAAAAAAtnZXRfc2Vzc2lvbgAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAB9AAAAAMU2Vzc2lvblN0YXRl",
+        "AAAAAAAAADlDYXAgdGhlIGNvbWJpbmVkIHN0YWtlIG9mIGEgZ2FtZS4gYE5vbmVgIHJlbW92ZXMgdGhlIGNhcC4AAAAAAAALc2V0X21heF9wb3QAAAAAAQAAAAAAAAAHbWF4X3BvdAAAAAPoAAAACwAAAAA=",
+        "AAAAAAAAAP5TdWJtaXQgYSBwaW5nIHJlc3VsdCB3aXRoIFpLIHByb29mIHZlcmlmaWNhdGlvbiAoTm9pciArIFVsdHJhSG9uaykuCgpQdWJsaWMgaW5wdXRzIGxheW91dCAoMTEgeCAzMi1ieXRlIGJpZy1lbmRpYW4gZmllbGQgZWxlbWVudHMpOgpbdmVyc2lvbiwgc2Vzc2lvbl9pZCwgdHVybiwgcGluZ194LCBwaW5nX3ksIGRyb3BfY29tbWl0bWVudCwgZXhwZWN0ZWRfZGlzdGFuY2UsIHRvcm9pZGFsLCBncmlkX3dpZHRoLCBncmlkX2hlaWdodCwgbWV0cmljXQAAAAAAC3N1Ym1pdF9waW5nAAAAAAgAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEdHVybgAAAAQAAAAAAAAACGRpc3RhbmNlAAAABAAAAAAAAAAGcGluZ194AAAAAAAEAAAAAAAAAAZwaW5nX3kAAAAAAAQAAAAAAAAABXByb29mAAAAAAAADgAAAAAAAAANcHVibGljX2lucHV0cwAAAAAAA+oAAAPuAAAAIAAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAEBDYW5jZWwgYW4gb3BlbiBsb2JieSBzbyBpdHMgc2Vzc2lvbl9pZCBjYW4gYmUgcmV1c2VkIHJpZ2h0IGF3YXkuAAAADGNhbmNlbF9sb2JieQAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABGhvc3QAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAH1WZXJpZmllZCBwaW5ncyBzbyBmYXIsIG9sZGVzdCBmaXJzdC4gSG9sZHMgYXQgbW9zdCBgbWF4X3R1cm5zYCBlbnRyaWVzOwppbiBiZXN0LW9mLU4gbWF0Y2hlcyBvbGRlciByb3VuZHMgcm9sbCBvZmYgdGhlIGZyb250LgAAAAAAAAxnZXRfcGluZ19sb2cAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAH0AAAAApQaW5nUmVjb3JkAAAAAAAD",
         "AAAAAAAAAAAAAAAMc2V0X3ZlcmlmaWVyAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
         "AAAAAAAAABhJbml0aWFsaXplIHRoZSBjb250cmFjdC4AAAANX19jb25zdHJ1Y3RvcgAAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAt2ZXJpZmllcl9pZAAAAAATAAAAAAAAABZyYW5kb21uZXNzX3ZlcmlmaWVyX2lkAAAAAAATAAAAAA==",
-        "AAAAAAAAADFGb3JjZSBhIHRpbWVvdXQgd2luIGlmIHRoZSBvcHBvbmVudCBoYXMgYmVlbiBBRksuAAAAAAAADWZvcmNlX3RpbWVvdXQAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAAMBGb3JjZSBhIHRpbWVvdXQgd2luIGlmIHRoZSBwbGF5ZXIgdG8gbW92ZSBoYXMgYmVlbiBBRksgZm9yIHRoZSBnYW1lJ3Mgc2hvdCBjbG9jay4KT25seSB0aGUgd2FpdGluZyBwbGF5ZXIgbWF5IGNsYWltIGl0LiBCZXR3ZWVuIHJvdW5kcyB0aGUgcGxheWVyIHdobyBvd2VzCmBuZXh0X3JvdW5kYCBpcyB0aGUgb25lIG9uIHRoZSBjbG9jay4AAAANZm9yY2VfdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAJlDb252ZXJ0IGxlZ2FjeSBgR2FtZShzZXNzaW9uX2lkKWAgZW50cmllcyB0byBgR2FtZVYyYCBhZnRlciBhbiB1cGdyYWRlLCBpbnN0ZWFkIG9mCndhaXRpbmcgZm9yIHRoZWlyIG5leHQgd3JpdGUuIFJldHVybnMgaG93IG1hbnkgc2Vzc2lvbnMgd2VyZSBtaWdyYXRlZC4AAAAAAAANbWlncmF0ZV9nYW1lcwAAAAAAAAEAAAAAAAAAC3Nlc3Npb25faWRzAAAAA+oAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAAFRBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgdG8gbW92ZSwgc28gY2xpZW50cyBkb24ndCBoYXZlIHRvIG1hcCBgd2hvc2VfdHVybmAgdGhlbXNlbHZlcy4AAAAOY3VycmVudF9wbGF5ZXIAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAAFdIYW5kIGFuIHVuam9pbmVkIGxvYmJ5IHRvIGEgbmV3IGhvc3QsIHdobyBzdGFrZXMgYG5ld19wb2ludHNgIGluIHBsYWNlIG9mIHRoZSBvbGQgaG9zdC4AAAAADnRyYW5zZmVyX2xvYmJ5AAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARob3N0AAAAEwAAAAAAAAAIbmV3X2hvc3QAAAATAAAAAAAAAApuZXdfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAONXaG8gcGluZ2VkIGZpcnN0IGluIHRoZSBjdXJyZW50IChvciBsYXN0IGZpbmlzaGVkKSByb3VuZDogMSA9IHBsYXllcjEsIDIgPSBwbGF5ZXIyLgoKVGhpcyBpcyB0aGUgb3BlbmVyIHJlc29sdmVkIHdoZW4gdGhlIHJvdW5kIHN0YXJ0ZWQuIEZvciBjb2luLWZsaXAgZ2FtZXMgaXQgY2FuIGJlCmNoZWNrZWQgYWdhaW5zdCB0aGUgcmFuZG9tbmVzcyBvdXRwdXQgaW4gdGhlIGZhaXJuZXNzIHByb29mLgAAAAAPZ2V0X2ZpcnN0X21vdmVyAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAD5UaGUgcGxheWVyJ3Mgb3duIHJlc3VtZSBoaW50IGZvciBhIHNlc3Npb24sIGlmIG9uZSB3YXMgc3RvcmVkLgAAAAAAD2dldF9yZXN1bWVfaGludAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6AAAAA4=",
+        "AAAAAAAAAIlQaW5nIGNvdW50cyBmb3IgYSBnYW1lIGFzIGAodG90YWxfcGluZ3MsIHBsYXllcjFfcGluZ3MsIHBsYXllcjJfcGluZ3MpYC4KClVubGlrZSBgY3VycmVudF90dXJuYCwgdGhlIHRvdGFsIGluY2x1ZGVzIGEgZmluYWwgd2lubmluZyBwaW5nLgAAAAAAAA9nZXRfdHVybl9jb3VudHMAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD7QAAAAMAAAAEAAAABAAAAAQAAAAD",
+        "AAAAAAAAAJ1TdGFzaCBhbiBvcGFxdWUgYmxvYiAodHlwaWNhbGx5IHRoZSBwbGF5ZXIncyBlbmNyeXB0ZWQgc2FsdCBhbmQgY29vcmRpbmF0ZXMpIHNvIGEKcmVsb2FkZWQgY2xpZW50IGNhbiByZXN1bWUgd2l0aG91dCBhIGJhY2tlbmQuIE92ZXJ3cml0ZXMgYW55IHByZXZpb3VzIGhpbnQuAAAAAAAAD3NldF9yZXN1bWVfaGludAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABGhpbnQAAAAOAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAElTdGF0aWMgZmVhdHVyZSBzZXQgb2YgdGhpcyBidWlsZDogZ3JpZCBsaW1pdHMsIG1ldHJpY3MgYW5kIHByb29mIHN5c3RlbXMuAAAAAAAAEGdldF9jYXBhYmlsaXRpZXMAAAAAAAAAAQAAB9AAAAAMQ2FwYWJpbGl0aWVz",
+        "AAAAAAAAAD1GaW5kIHRoZSBwbGF5ZXIncyBtb3N0IHJlY2VudCBnYW1lIHRoYXQgaGFzIG5vdCBmaW5pc2hlZCB5ZXQuAAAAAAAAEGdldF9jdXJyZW50X2dhbWUAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAACAAAABAAAB9AAAAAER2FtZQAAAAM=",
+        "AAAAAAAAADxMaWdodHdlaWdodCB2aWV3IG9mIGEgZ2FtZSBmb3IgbGVhZGVyYm9hcmRzIGFuZCBsb2JieSBsaXN0cy4AAAAQZ2V0X2dhbWVfc3VtbWFyeQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAALR2FtZVN1bW1hcnkAAAAAAw==",
+        "AAAAAAAAAD1TZXNzaW9uIGlkcyBvZiBsb2JiaWVzIHdhaXRpbmcgZm9yIGFuIG9wcG9uZW50LCBvbGRlc3QgZmlyc3QuAAAAAAAAEGdldF9vcGVuX2xvYmJpZXMAAAAAAAAAAQAAA+oAAAAE",
+        "AAAAAAAAAAAAAAARZ2V0X3N0cmljdF9wcm9vZnMAAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAPBSZXRyYWN0IHRoZSBjYWxsZXIncyBtb3N0IHJlY2VudCBwaW5nIGluIGdhbWVzIHRoYXQgYWxsb3cgaXQuCgpPbmx5IHZhbGlkIGJlZm9yZSB0aGUgb3Bwb25lbnQgcGluZ3MgYW5kIHdpdGhpbiBgUkVUUkFDVF9XSU5ET1dfTEVER0VSU2AsIGFuZCBhdAptb3N0IG9uY2UgcGVyIHR1cm4uIFJlc3RvcmVzIHRoZSB0dXJuLCB0aGUgcGluZ2VyJ3MgcGluZyBjb3VudCBhbmQgdGhlaXIgcHJldmlvdXMKYmVzdCBkaXN0YW5jZS4AAAARcmV0cmFjdF9sYXN0X3BpbmcAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAFVSYW5rZWQgbW9kZTogYSByZWplY3RlZCBwaW5nIHByb29mIGZvcmZlaXRzIHRoZSBnYW1lIHRvIHRoZSBvcHBvbmVudC4gT2ZmIGJ5IGRlZmF1bHQuAAAAAAAAEXNldF9zdHJpY3RfcHJvb2ZzAAAAAAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+        "AAAAAAAAASJSZWFkIHRoZSByYW5kb21uZXNzIGFydGlmYWN0cyB0aGF0IGJvdW5kIHRoZSBkcm9wIGNvbW1pdG1lbnQgYXQgZ2FtZSBzdGFydC4KCkxheW91dDogYHJhbmRvbW5lc3Nfb3V0cHV0ICgzMikgfHwgZHJvcF9jb21taXRtZW50ICgzMikgfHwgcmFuZG9tbmVzc19zaWduYXR1cmUgKDY0KWAuCkF1ZGl0b3JzIGNhbiBzcGxpdCB0aGVzZSBieXRlcyBhbmQgcmUtZmVlZCB0aGVtLCB0b2dldGhlciB3aXRoIGBzZXNzaW9uX2lkYCwKdG8gdGhlIHJhbmRvbW5lc3MgdmVyaWZpZXIncyBgdmVyaWZ5X3JhbmRvbW5lc3NgLgAAAAAAEmdldF9mYWlybmVzc19wcm9vZgAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAADgAAAAM=",
+        "AAAAAAAAAJxUb3RhbCBwb2ludHMgYXQgcmlzayBmb3IgYSBzZXNzaW9uOiBib3RoIHN0YWtlcyBmb3IgYSBnYW1lLCBvciB0aGUgaG9zdCdzCnN0YWtlIGZvciBhbiBvcGVuIGxvYmJ5LiBQb2ludHMgYXJlIGNvbW1pdHRlZCB2aWEgYXV0aCwgbm90IGhlbGQgYnkgdGhpcyBjb250cmFjdC4AAAATZ2V0X2NvbW1pdHRlZF9zdGFrZQAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAFRUdXJucyBsZWZ0IGJlZm9yZSB0aGUgZ2FtZSBpcyBkZWNpZGVkIGJ5IGJlc3QgZGlzdGFuY2UgKGBtYXhfdHVybnMgLSBjdXJyZW50X3R1cm5gKS4AAAATZ2V0X3JlbWFpbmluZ190dXJucwAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAJ5MZWRnZXJzIHVudGlsIHRoZSB3YWl0aW5nIHBsYXllciBtYXkgY2FsbCBgZm9yY2VfdGltZW91dGA7IGAwYCBvbmNlIHRoZSBjbG9jayBoYXMgcnVuIG91dApvciB3aGVuIHRoZXJlIGlzIG5vIHN1Y2ggZ2FtZS4gVGhlIGRlYWRsaW5lIHNhdHVyYXRlcyBhdCBgdTMyOjpNQVhgLgAAAAAAE3R1cm5zX3VudGlsX3RpbWVvdXQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAKZQdWJsaWMgaW5wdXQgY291bnQgYHN1Ym1pdF9waW5nYCBleHBlY3RzIGZyb20gdGhlIGNpcmN1aXQuIFRoZXJlIGlzIG5vIHNldHRlcjogdGhlCmNvdW50IGlzIGZpeGVkIGJ5IHRoZSBjaXJjdWl0IGxheW91dCwgd2hpY2ggYGJ1aWxkX3B1YmxpY19pbnB1dHNgIGZvbGxvd3MgYnkgaW5kZXguAAAAAAAVZ2V0X251bV9wdWJsaWNfaW5wdXRzAAAAAAAAAAAAAAEAAAAE",
         "AAAAAAAAAAAAAAAXZ2V0X3JhbmRvbW5lc3NfdmVyaWZpZXIAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAXc2V0X3JhbmRvbW5lc3NfdmVyaWZpZXIAAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=" ]),
+        "AAAAAAAAAAAAAAAXc2V0X3JhbmRvbW5lc3NfdmVyaWZpZXIAAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAZZ2V0X3B1YmxpY19pbnB1dHNfdmVyc2lvbgAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAH9TZXQgdGhlIGxheW91dCB2ZXJzaW9uIGJvdW5kIGFzIHRoZSBmaXJzdCBwdWJsaWMgaW5wdXQuIFByb29mcyBidWlsdCBhZ2FpbnN0CmFueSBvdGhlciB2ZXJzaW9uIGZhaWwgd2l0aCBgSW52YWxpZFB1YmxpY0lucHV0c2AuAAAAABlzZXRfcHVibGljX2lucHV0c192ZXJzaW9uAAAAAAAAAQAAAAAAAAAHdmVyc2lvbgAAAAAEAAAAAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
+    concede: this.txFromJSON<Result<string>>,
+        get_hub: this.txFromJSON<string>,
+        rematch: this.txFromJSON<Result<void>>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
         get_game: this.txFromJSON<Result<Game>>,
+        bump_game: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
+        get_games: this.txFromJSON<Array<Option<Game>>>,
         get_lobby: this.txFromJSON<Result<Lobby>>,
+        get_rules: this.txFromJSON<Result<RuleSet>>,
+        get_stats: this.txFromJSON<PlayerStats>,
+        is_paused: this.txFromJSON<boolean>,
         join_game: this.txFromJSON<Result<void>>,
         open_game: this.txFromJSON<Result<void>>,
         set_admin: this.txFromJSON<null>,
+        next_round: this.txFromJSON<Result<void>>,
+        reap_lobby: this.txFromJSON<Result<void>>,
+        set_paused: this.txFromJSON<null>,
         start_game: this.txFromJSON<Result<void>>,
+        admin_abort: this.txFromJSON<Result<void>>,
+        get_max_pot: this.txFromJSON<Option<i128>>,
+        get_outcome: this.txFromJSON<Result<Outcome>>,
+        get_session: this.txFromJSON<SessionState>,
+        set_max_pot: this.txFromJSON<null>,
         submit_ping: this.txFromJSON<Result<Option<string>>>,
+        cancel_lobby: this.txFromJSON<Result<void>>,
+        get_ping_log: this.txFromJSON<Result<Array<PingRecord>>>,
         set_verifier: this.txFromJSON<null>,
         force_timeout: this.txFromJSON<Result<string>>,
+        migrate_games: this.txFromJSON<u32>,
+        current_player: this.txFromJSON<Result<string>>,
+        transfer_lobby: this.txFromJSON<Result<void>>,
+        get_first_mover: this.txFromJSON<Result<u32>>,
+        get_resume_hint: this.txFromJSON<Option<Buffer>>,
+        get_turn_counts: this.txFromJSON<Result<readonly [u32, u32, u32]>>,
+        set_resume_hint: this.txFromJSON<Result<void>>,
+        get_capabilities: this.txFromJSON<Capabilities>,
+        get_current_game: this.txFromJSON<Result<readonly [u32, Game]>>,
+        get_game_summary: this.txFromJSON<Result<GameSummary>>,
+        get_open_lobbies: this.txFromJSON<Array<u32>>,
+        get_strict_proofs: this.txFromJSON<boolean>,
+        retract_last_ping: this.txFromJSON<Result<void>>,
+        set_strict_proofs: this.txFromJSON<null>,
+        get_fairness_proof: this.txFromJSON<Result<Buffer>>,
+        get_committed_stake: this.txFromJSON<Result<i128>>,
+        get_remaining_turns: this.txFromJSON<Result<u32>>,
+        turns_until_timeout: this.txFromJSON<u32>,
+        get_num_public_inputs: this.txFromJSON<u32>,
         get_randomness_verifier: this.txFromJSON<string>,
-        set_randomness_verifier: this.txFromJSON<null>
+        set_randomness_verifier: this.txFromJSON<null>,
+        get_public_inputs_version: this.txFromJSON<u32>,
+        set_public_inputs_version: this.txFromJSON<null>
   }
 }
\ No newline at end of file
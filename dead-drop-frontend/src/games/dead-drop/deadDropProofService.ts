@@ -5,13 +5,26 @@ export interface SessionRandomnessArtifacts {
   dropCommitmentHex: string;
 }
 
+/** Board rules from the game's on-chain config, bound into the proof's public inputs. */
+export interface PingRules {
+  toroidal: boolean;
+  gridWidth: number;
+  gridHeight: number;
+  metric: number;
+}
+
 export interface ProvePingRequest {
   sessionId: number;
   turn: number;
   pingX: number;
   pingY: number;
+  /** Omit to use the contract's default config. */
+  rules?: PingRules;
 }
 
+/** Must match NUM_PUBLIC_INPUTS in contracts/dead-drop/src/lib.rs. */
+const NUM_PUBLIC_INPUTS = 11;
+
 export interface ProvePingResponse {
   distance: number;
   proofHex: string;
@@ -54,18 +67,24 @@ function ensureHex(value: unknown, label: string, expectedBytes?: number): strin
 export async function getSessionRandomness(
   proverUrl: string,
   sessionId: number,
+  grid?: { width: number; height: number },
 ): Promise<SessionRandomnessArtifacts> {
   const normalizedProverUrl = proverUrl.replace(/\/$/, '');
   if (DEAD_DROP_DEBUG) {
     console.info('[DeadDropProof][randomness] Request start', {
       proverUrl: normalizedProverUrl,
       sessionId,
+      grid,
     });
   }
   const response = await fetch(`${normalizedProverUrl}/randomness/session`, {
     method: 'POST',
     headers: { 'Content-Type': 'application/json' },
-    body: JSON.stringify({ session_id: sessionId }),
+    body: JSON.stringify({
+      session_id: sessionId,
+      grid_width: grid?.width,
+      grid_height: grid?.height,
+    }),
   });
 
   if (DEAD_DROP_DEBUG) {
@@ -121,6 +140,10 @@ export async function provePing(
       turn: req.turn,
       ping_x: req.pingX,
       ping_y: req.pingY,
+      toroidal: req.rules?.toroidal,
+      grid_width: req.rules?.gridWidth,
+      grid_height: req.rules?.gridHeight,
+      metric: req.rules?.metric,
     }),
   });
 
@@ -141,10 +164,10 @@ export async function provePing(
   if (!Array.isArray(data.public_inputs_hex)) {
     throw new Error('Proof service returned missing public_inputs_hex');
   }
-  if (data.public_inputs_hex.length !== 6) {
+  if (data.public_inputs_hex.length !== NUM_PUBLIC_INPUTS) {
     throw new Error(
       `Dead Drop prover API mismatch at ${normalizedProverUrl}. ` +
-      `Expected 6 public inputs, got ${data.public_inputs_hex.length}.`
+      `Expected ${NUM_PUBLIC_INPUTS} public inputs, got ${data.public_inputs_hex.length}.`
     );
   }
 
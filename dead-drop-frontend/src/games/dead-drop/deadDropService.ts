@@ -1,4 +1,4 @@
-import { Client as DeadDropClient, type Game, type Lobby } from './bindings';
+import { Client as DeadDropClient, type Game, type GameConfig, type Lobby } from './bindings';
 import {
   NETWORK_PASSPHRASE,
   RPC_URL,
@@ -21,6 +21,24 @@ type ClientSigner = Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEn
 type MutationSigner = ClientSigner;
 const DEAD_DROP_DEBUG = import.meta.env.DEV || import.meta.env.VITE_DEAD_DROP_DEBUG === 'true';
 
+/** Mirrors `GameConfig::default()` in contracts/dead-drop/src/lib.rs. */
+export const DEFAULT_GAME_CONFIG: GameConfig = {
+  toroidal: true,
+  metric: 0,
+  min_pings_before_win: 0,
+  retracts_allowed: false,
+  grid_width: 100,
+  grid_height: 100,
+  max_turns: 30,
+  coin_flip_first_mover: false,
+  rounds_to_win: 1,
+  turn_timeout_ledgers: 600,
+  scoring_mode: 0,
+  player1_handicap: 0,
+  player2_handicap: 0,
+  player1_wins_ties: false,
+};
+
 /**
  * Service for interacting with the Dead Drop game contract.
  *
@@ -485,7 +503,8 @@ export class DeadDropService {
     player2Points: bigint,
     randomness: DeadDropRandomnessArtifacts,
     player1Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    config: GameConfig = DEFAULT_GAME_CONFIG
   ): Promise<string> {
     const buildClient = new DeadDropClient({
       contractId: this.contractId,
@@ -503,6 +522,7 @@ export class DeadDropService {
       randomness_output: randomness.randomnessOutput,
       drop_commitment: randomness.dropCommitment,
       randomness_signature: randomness.randomnessSignature,
+      config,
     }, DEFAULT_METHOD_OPTIONS);
 
     if (!tx.simulationData?.result?.auth) {
@@ -586,7 +606,8 @@ export class DeadDropService {
     player2Points: bigint,
     randomness: DeadDropRandomnessArtifacts,
     player2Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    config: GameConfig = DEFAULT_GAME_CONFIG
   ): Promise<string> {
     const gameParams = this.parseAuthEntry(player1SignedAuthEntryXdr);
     if (player2Address === gameParams.player1) {
@@ -609,6 +630,7 @@ export class DeadDropService {
       randomness_output: randomness.randomnessOutput,
       drop_commitment: randomness.dropCommitment,
       randomness_signature: randomness.randomnessSignature,
+      config,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntilLedgerSeq = await calculateValidUntilLedger(
@@ -666,7 +688,8 @@ export class DeadDropService {
     player1Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     player2Signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     stakePoints: bigint,
-    authTtlMinutes?: number
+    authTtlMinutes?: number,
+    config: GameConfig = DEFAULT_GAME_CONFIG
   ): Promise<void> {
     // Build transaction from player 2's perspective (as the invoker) with signer
     const buildClient = new DeadDropClient({
@@ -686,6 +709,7 @@ export class DeadDropService {
       randomness_output: randomness.randomnessOutput,
       drop_commitment: randomness.dropCommitment,
       randomness_signature: randomness.randomnessSignature,
+      config,
     }, DEFAULT_METHOD_OPTIONS);
 
     if (!tx.simulationData?.result?.auth) {
@@ -767,13 +791,16 @@ export class DeadDropService {
     sessionId: number,
     hostAddress: string,
     hostPoints: bigint,
-    signer: MutationSigner
+    signer: MutationSigner,
+    config: GameConfig = DEFAULT_GAME_CONFIG,
+    expectedOpponent?: string
   ) {
     if (DEAD_DROP_DEBUG) {
       console.info('[DeadDropService][open_game] Build start', {
         sessionId,
         hostAddress,
         hostPoints: hostPoints.toString(),
+        expectedOpponent,
       });
     }
     const mutationPlan = this.planMutationClient(hostAddress, this.toClientSigner(signer));
@@ -782,6 +809,8 @@ export class DeadDropService {
       session_id: sessionId,
       host: hostAddress,
       host_points: hostPoints,
+      config,
+      expected_opponent: expectedOpponent,
     }, DEFAULT_METHOD_OPTIONS);
     if (DEAD_DROP_DEBUG) {
       console.info('[DeadDropService][open_game] Build complete', {